@@ -3,23 +3,249 @@
 use bevy::ecs::message::Message;
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
-use crate::config::RESULTS_DIR;
-use crate::metrics::SampleStats;
+use crate::config::STABILITY_CV_THRESHOLD;
+use crate::metrics::{format_bytes, format_count, format_duration, format_throughput, SampleStats};
 use crate::state::SelectedWorkload;
 
+/// Version of the report JSON layout. Bump whenever the structure changes.
+///
+/// 2 added the per-result search, timing and variant details. They are all
+/// optional, so version 1 reports still load with those fields defaulted.
+pub const REPORT_SCHEMA_VERSION: u32 = 2;
+
 /// A single workload's benchmark result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkloadResult {
     pub workload_name: String,
     pub workload_description: String,
+    /// Sub-variant of the workload that was measured, for workloads that have them
+    #[serde(default)]
+    pub variant: Option<String>,
     pub breakdown_point: usize,
+    /// False when the search hit the entity limit without exceeding the
+    /// target; `breakdown_point` is then the limit, not a breakdown
+    #[serde(default = "default_true")]
+    pub breakdown_found: bool,
     pub throughput_at_breakdown: f64,
+    /// Work units per entity for this workload (see `WorkloadWeights`)
+    #[serde(default)]
+    pub work_weight: f64,
+    /// Throughput scaled by `work_weight`, comparable across workloads
+    #[serde(default)]
+    pub work_units_per_sec: f64,
+    pub target_frame_time_ms: f64,
+    /// Total structural operations over total time in the final sampling window
+    pub structural_ops_per_sec: f64,
+    pub archetype_count: usize,
+    /// Archetypes holding entities when the result was recorded
+    #[serde(default)]
+    pub populated_archetype_count: usize,
+    /// Fraction of entities churned per frame (0 for workloads without churn)
+    #[serde(default)]
+    pub churn_rate: f32,
+    /// Entities per `spawn_batch` call (chunked spawn workload only, else 0)
+    #[serde(default)]
+    pub spawn_chunk_size: usize,
+    /// Frame time coefficient of variation (std_dev / mean) of the final samples
+    #[serde(default)]
+    pub stability_cv: f64,
+    /// Non-finite frame samples discarded during the search (nonzero = anomalies)
+    #[serde(default)]
+    pub dropped_samples: usize,
+    /// Wall-clock time from the workload's first warm-up to convergence
+    #[serde(default)]
+    pub convergence_seconds: f64,
+    /// `BenchmarkEntity` count found after spawning in the final search iteration
+    #[serde(default)]
+    pub spawned_count: usize,
+    /// Some iteration spawned a different count than requested
+    #[serde(default)]
+    pub spawn_mismatch: bool,
+    /// Warm-up frames spent across the whole search (varies with adaptive warm-up)
+    #[serde(default)]
+    pub warmup_frames: usize,
+    /// Median frame time minus the report's empty-world baseline (None without a baseline)
+    #[serde(default)]
+    pub net_frame_time_ms: Option<f64>,
+    /// Fraction of frames where the Disjoint Parallel systems ran at once
+    #[serde(default)]
+    pub parallel_overlap: Option<f64>,
+    /// Tasks `par_iter_mut` split one frame's work into, for the mutation workloads
+    #[serde(default)]
+    pub par_iter_tasks: Option<usize>,
+    /// Per-frame cost of a fixed-cost workload, net of the baseline when the
+    /// report has one; replaces the breakdown point
+    #[serde(default)]
+    pub frame_overhead_ms: Option<f64>,
+    /// Cache-warming passes run at the end of each warm-up before sampling
+    #[serde(default)]
+    pub cache_warm_passes: usize,
+    /// Defrag Recovery: median iteration time before the markers were stripped
+    #[serde(default)]
+    pub fragmented_iteration_ms: Option<f64>,
+    /// Defrag Recovery: median iteration time after collapsing to one archetype
+    #[serde(default)]
+    pub recovered_iteration_ms: Option<f64>,
+    /// Bulk Despawn: mean time of the timed teardown alone over the sampled
+    /// frames (the frame time also includes rebuilding the scratch World)
+    #[serde(default)]
+    pub teardown_ms: Option<f64>,
+    /// Whether reads went through `black_box` (None for workloads without the toggle)
+    #[serde(default)]
+    pub black_box_reads: Option<bool>,
+    /// Slowest frame of the final sample window
+    #[serde(default)]
+    pub worst_spike_ms: f64,
+    /// Index of that frame within the sample window
+    #[serde(default)]
+    pub worst_spike_frame: usize,
+    /// Inline component bytes each entity of the workload carries
+    #[serde(default)]
+    pub bytes_per_entity: usize,
+    /// Runs of this workload it took to get this result (1 unless the suite
+    /// retried noisy results)
+    #[serde(default = "default_attempts")]
+    pub attempts: usize,
+    /// Entity count with the highest total throughput among the counts the
+    /// search measured: where adding entities stopped paying off
+    #[serde(default)]
+    pub knee_count: Option<usize>,
+    /// Throughput (entities/sec) measured at `knee_count`
+    #[serde(default)]
+    pub knee_throughput: Option<f64>,
+    /// Generator behind `FastRng` for this run (see `RngBackend::name`)
+    #[serde(default)]
+    pub rng_backend: String,
+    /// Mean absolute difference between consecutive sampled frames
+    #[serde(default)]
+    pub jitter_ms: f64,
+    /// Component Add/Remove: share of each group migrated per toggle
+    #[serde(default)]
+    pub toggle_fraction: Option<f32>,
+    /// Component Add/Remove: frames between toggles
+    #[serde(default)]
+    pub toggle_period: Option<usize>,
+    /// Component Add/Remove: per-group cap on each toggle (None if uncapped)
+    #[serde(default)]
+    pub toggle_max: Option<usize>,
     pub frame_time_stats: FrameTimeStats,
 }
 
+impl WorkloadResult {
+    /// Create a result with the core measurements; optional metrics start at zero
+    pub fn new(
+        workload: SelectedWorkload,
+        breakdown_point: usize,
+        throughput: f64,
+        target_ms: f64,
+        stats: SampleStats,
+    ) -> Self {
+        Self {
+            workload_name: workload.name().to_string(),
+            workload_description: workload.description().to_string(),
+            variant: None,
+            breakdown_point,
+            breakdown_found: true,
+            throughput_at_breakdown: throughput,
+            work_weight: 1.0,
+            work_units_per_sec: throughput,
+            target_frame_time_ms: target_ms,
+            structural_ops_per_sec: 0.0,
+            archetype_count: 0,
+            populated_archetype_count: 0,
+            churn_rate: 0.0,
+            spawn_chunk_size: 0,
+            stability_cv: stats.cv(),
+            dropped_samples: stats.dropped,
+            convergence_seconds: 0.0,
+            spawned_count: 0,
+            spawn_mismatch: false,
+            warmup_frames: 0,
+            net_frame_time_ms: None,
+            parallel_overlap: None,
+            par_iter_tasks: None,
+            frame_overhead_ms: None,
+            cache_warm_passes: 0,
+            fragmented_iteration_ms: None,
+            recovered_iteration_ms: None,
+            teardown_ms: None,
+            black_box_reads: None,
+            worst_spike_ms: 0.0,
+            worst_spike_frame: 0,
+            bytes_per_entity: 0,
+            attempts: 1,
+            knee_count: None,
+            knee_throughput: None,
+            rng_backend: String::new(),
+            jitter_ms: stats.jitter_ms,
+            toggle_fraction: None,
+            toggle_period: None,
+            toggle_max: None,
+            frame_time_stats: stats.into(),
+        }
+    }
+
+    /// Workload name with the variant appended, e.g. "Bitflag Branching (checks)"
+    pub fn display_name(&self) -> String {
+        match &self.variant {
+            Some(variant) => format!("{} ({})", self.workload_name, variant),
+            None => self.workload_name.clone(),
+        }
+    }
+
+    /// Breakdown point for display: "> N" when no breakdown was found below
+    /// the limit, the per-frame overhead for fixed-cost workloads
+    pub fn breakdown_label(&self) -> String {
+        if let Some(overhead) = self.frame_overhead_ms {
+            format!("{:.3}ms", overhead)
+        } else if self.breakdown_found {
+            format_count(self.breakdown_point)
+        } else {
+            format!("> {}", format_count(self.breakdown_point))
+        }
+    }
+
+    /// Peak throughput of the search and the count it was measured at, e.g.
+    /// "1.20B/s @ 400K" ("-" for fixed-cost workloads and older reports).
+    ///
+    /// This is the knee: it can sit below the breakdown point when frame
+    /// time starts growing faster than the entity count before the target.
+    pub fn peak_label(&self) -> String {
+        match (self.knee_throughput, self.knee_count) {
+            (Some(throughput), Some(count)) => {
+                format!(
+                    "{} @ {}",
+                    format_throughput(throughput),
+                    format_count(count)
+                )
+            }
+            _ => "-".to_string(),
+        }
+    }
+
+    /// Component memory at the breakdown point: breakdown × `bytes_per_entity`
+    pub fn total_bytes(&self) -> usize {
+        self.breakdown_point.saturating_mul(self.bytes_per_entity)
+    }
+
+    /// Whether the frame times were too variable to trust the breakdown point
+    pub fn is_noisy(&self) -> bool {
+        self.stability_cv > STABILITY_CV_THRESHOLD
+    }
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_attempts() -> usize {
+    1
+}
+
 /// Frame time statistics for a result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrameTimeStats {
@@ -30,6 +256,9 @@ pub struct FrameTimeStats {
     pub std_dev_ms: f64,
     pub p95_ms: f64,
     pub p99_ms: f64,
+    /// All computed percentiles, keyed by basis points (99.9 -> 9990)
+    #[serde(default)]
+    pub percentiles_ms: BTreeMap<u32, f64>,
 }
 
 impl From<SampleStats> for FrameTimeStats {
@@ -40,9 +269,231 @@ impl From<SampleStats> for FrameTimeStats {
             median_ms: stats.median,
             mean_ms: stats.mean,
             std_dev_ms: stats.std_dev,
-            p95_ms: stats.p95,
-            p99_ms: stats.p99,
+            p95_ms: stats.p95(),
+            p99_ms: stats.p99(),
+            percentiles_ms: stats.percentiles.into_iter().collect(),
+        }
+    }
+}
+
+impl FrameTimeStats {
+    /// Field-wise mean of several stats (None if empty)
+    pub fn mean_of(stats: &[&FrameTimeStats]) -> Option<Self> {
+        if stats.is_empty() {
+            return None;
         }
+        let n = stats.len() as f64;
+        let avg = |f: fn(&FrameTimeStats) -> f64| stats.iter().map(|s| f(*s)).sum::<f64>() / n;
+
+        Some(Self {
+            min_ms: avg(|s| s.min_ms),
+            max_ms: avg(|s| s.max_ms),
+            median_ms: avg(|s| s.median_ms),
+            mean_ms: avg(|s| s.mean_ms),
+            std_dev_ms: avg(|s| s.std_dev_ms),
+            p95_ms: avg(|s| s.p95_ms),
+            p99_ms: avg(|s| s.p99_ms),
+            percentiles_ms: stats[0]
+                .percentiles_ms
+                .keys()
+                .map(|&key| {
+                    let sum: f64 = stats
+                        .iter()
+                        .map(|s| s.percentiles_ms.get(&key).copied().unwrap_or(0.0))
+                        .sum();
+                    (key, sum / n)
+                })
+                .collect(),
+        })
+    }
+}
+
+/// A workload's results aggregated across repeated suite runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregatedResult {
+    /// Number of runs aggregated
+    pub runs: usize,
+    /// Mean of each field across runs
+    pub mean: WorkloadResult,
+    /// Standard deviation of the breakdown point across runs
+    pub breakdown_std_dev: f64,
+    /// Standard deviation of the throughput at breakdown across runs
+    pub throughput_std_dev: f64,
+}
+
+impl AggregatedResult {
+    /// Aggregate several runs of the same workload (None if empty)
+    pub fn from_runs(runs: &[&WorkloadResult]) -> Option<Self> {
+        let first = runs.first()?;
+        let n = runs.len() as f64;
+        // Worst spike across every run, keeping the frame it happened on
+        let worst = runs
+            .iter()
+            .max_by(|a, b| a.worst_spike_ms.total_cmp(&b.worst_spike_ms))
+            .unwrap_or(first);
+
+        let breakdowns: Vec<f64> = runs.iter().map(|r| r.breakdown_point as f64).collect();
+        let throughputs: Vec<f64> = runs.iter().map(|r| r.throughput_at_breakdown).collect();
+        let (breakdown_mean, breakdown_std_dev) = mean_and_std_dev(&breakdowns);
+        let (throughput_mean, throughput_std_dev) = mean_and_std_dev(&throughputs);
+
+        let stats: Vec<&FrameTimeStats> = runs.iter().map(|r| &r.frame_time_stats).collect();
+
+        let mean = WorkloadResult {
+            workload_name: first.workload_name.clone(),
+            workload_description: first.workload_description.clone(),
+            variant: first.variant.clone(),
+            breakdown_point: breakdown_mean.round() as usize,
+            breakdown_found: runs.iter().all(|r| r.breakdown_found),
+            throughput_at_breakdown: throughput_mean,
+            work_weight: first.work_weight,
+            work_units_per_sec: throughput_mean * first.work_weight,
+            target_frame_time_ms: first.target_frame_time_ms,
+            structural_ops_per_sec: runs.iter().map(|r| r.structural_ops_per_sec).sum::<f64>() / n,
+            archetype_count: (runs.iter().map(|r| r.archetype_count).sum::<usize>() as f64 / n)
+                .round() as usize,
+            populated_archetype_count: first.populated_archetype_count,
+            churn_rate: first.churn_rate,
+            spawn_chunk_size: first.spawn_chunk_size,
+            stability_cv: runs.iter().map(|r| r.stability_cv).sum::<f64>() / n,
+            dropped_samples: runs.iter().map(|r| r.dropped_samples).sum(),
+            convergence_seconds: runs.iter().map(|r| r.convergence_seconds).sum::<f64>() / n,
+            spawned_count: first.spawned_count,
+            spawn_mismatch: runs.iter().any(|r| r.spawn_mismatch),
+            warmup_frames: (runs.iter().map(|r| r.warmup_frames).sum::<usize>() as f64 / n).round()
+                as usize,
+            net_frame_time_ms: {
+                let nets: Vec<f64> = runs.iter().filter_map(|r| r.net_frame_time_ms).collect();
+                (!nets.is_empty()).then(|| nets.iter().sum::<f64>() / nets.len() as f64)
+            },
+            parallel_overlap: {
+                let overlaps: Vec<f64> = runs.iter().filter_map(|r| r.parallel_overlap).collect();
+                (!overlaps.is_empty()).then(|| overlaps.iter().sum::<f64>() / overlaps.len() as f64)
+            },
+            par_iter_tasks: {
+                let tasks: Vec<usize> = runs.iter().filter_map(|r| r.par_iter_tasks).collect();
+                (!tasks.is_empty()).then(|| {
+                    (tasks.iter().sum::<usize>() as f64 / tasks.len() as f64).round() as usize
+                })
+            },
+            frame_overhead_ms: {
+                let overheads: Vec<f64> = runs.iter().filter_map(|r| r.frame_overhead_ms).collect();
+                (!overheads.is_empty())
+                    .then(|| overheads.iter().sum::<f64>() / overheads.len() as f64)
+            },
+            cache_warm_passes: first.cache_warm_passes,
+            fragmented_iteration_ms: {
+                let times: Vec<f64> = runs
+                    .iter()
+                    .filter_map(|r| r.fragmented_iteration_ms)
+                    .collect();
+                (!times.is_empty()).then(|| times.iter().sum::<f64>() / times.len() as f64)
+            },
+            recovered_iteration_ms: {
+                let times: Vec<f64> = runs
+                    .iter()
+                    .filter_map(|r| r.recovered_iteration_ms)
+                    .collect();
+                (!times.is_empty()).then(|| times.iter().sum::<f64>() / times.len() as f64)
+            },
+            teardown_ms: {
+                let times: Vec<f64> = runs.iter().filter_map(|r| r.teardown_ms).collect();
+                (!times.is_empty()).then(|| times.iter().sum::<f64>() / times.len() as f64)
+            },
+            black_box_reads: first.black_box_reads,
+            worst_spike_ms: worst.worst_spike_ms,
+            worst_spike_frame: worst.worst_spike_frame,
+            bytes_per_entity: first.bytes_per_entity,
+            attempts: runs.iter().map(|r| r.attempts).max().unwrap_or(1),
+            knee_count: {
+                let counts: Vec<usize> = runs.iter().filter_map(|r| r.knee_count).collect();
+                (!counts.is_empty()).then(|| {
+                    (counts.iter().sum::<usize>() as f64 / counts.len() as f64).round() as usize
+                })
+            },
+            knee_throughput: {
+                let throughputs: Vec<f64> = runs.iter().filter_map(|r| r.knee_throughput).collect();
+                (!throughputs.is_empty())
+                    .then(|| throughputs.iter().sum::<f64>() / throughputs.len() as f64)
+            },
+            rng_backend: first.rng_backend.clone(),
+            jitter_ms: runs.iter().map(|r| r.jitter_ms).sum::<f64>() / n,
+            toggle_fraction: first.toggle_fraction,
+            toggle_period: first.toggle_period,
+            toggle_max: first.toggle_max,
+            frame_time_stats: FrameTimeStats::mean_of(&stats)?,
+        };
+
+        Some(Self {
+            runs: runs.len(),
+            mean,
+            breakdown_std_dev,
+            throughput_std_dev,
+        })
+    }
+}
+
+/// Population mean and standard deviation
+fn mean_and_std_dev(values: &[f64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    (mean, variance.sqrt())
+}
+
+/// One workload's throughput in two reports
+#[derive(Debug, Clone)]
+pub struct WorkloadComparison {
+    pub name: String,
+    /// Throughput at breakdown in report A (None if A didn't run it)
+    pub throughput_a: Option<f64>,
+    /// Throughput at breakdown in report B (None if B didn't run it)
+    pub throughput_b: Option<f64>,
+}
+
+impl WorkloadComparison {
+    /// Change from A to B in percent (positive = B is faster)
+    pub fn change_percent(&self) -> Option<f64> {
+        match (self.throughput_a, self.throughput_b) {
+            (Some(a), Some(b)) if a > 0.0 => Some((b - a) / a * 100.0),
+            _ => None,
+        }
+    }
+}
+
+/// Two saved reports loaded with `--compare a.json b.json`, shown in the Results state
+#[derive(Resource, Debug, Clone)]
+pub struct ReportComparison {
+    pub label_a: String,
+    pub label_b: String,
+    pub rows: Vec<WorkloadComparison>,
+}
+
+impl ReportComparison {
+    /// Load both reports and compare them
+    pub fn load(path_a: &Path, path_b: &Path) -> Result<Self, String> {
+        let a = BenchmarkReport::load(path_a)?;
+        let b = BenchmarkReport::load(path_b)?;
+        if a.system_info.build_profile != b.system_info.build_profile {
+            warn!(
+                "Comparing a {} build against a {} build; the numbers aren't comparable",
+                a.system_info.build_profile, b.system_info.build_profile
+            );
+        }
+        let label = |path: &Path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.display().to_string())
+        };
+
+        Ok(Self {
+            label_a: label(path_a),
+            label_b: label(path_b),
+            rows: a.compare(&b),
+        })
     }
 }
 
@@ -52,36 +503,92 @@ pub struct SystemInfo {
     pub os: String,
     pub cpu_cores: usize,
     pub bevy_version: String,
+    /// "debug" or "release" (from `debug_assertions`); debug results aren't comparable
+    #[serde(default)]
+    pub build_profile: String,
+    /// `rustc -V` output captured by the build script
+    #[serde(default)]
+    pub rustc_version: String,
 }
 
 impl Default for SystemInfo {
     fn default() -> Self {
         Self {
-            os: std::env::consts::OS.to_string(),
-            cpu_cores: std::thread::available_parallelism()
-                .map(|p| p.get())
-                .unwrap_or(1),
+            os: host_os(),
+            cpu_cores: host_cpu_cores(),
             bevy_version: "0.17.3".to_string(),
+            build_profile: if cfg!(debug_assertions) {
+                "debug"
+            } else {
+                "release"
+            }
+            .to_string(),
+            rustc_version: env!("BENCH_RUSTC_VERSION").to_string(),
         }
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn host_os() -> String {
+    std::env::consts::OS.to_string()
+}
+
+/// `std::env::consts::OS` is "unknown" in the browser, which reads like a bug in reports
+#[cfg(target_arch = "wasm32")]
+fn host_os() -> String {
+    "wasm32".to_string()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn host_cpu_cores() -> usize {
+    std::thread::available_parallelism()
+        .map(|p| p.get())
+        .unwrap_or(1)
+}
+
+/// The browser build runs the schedule on a single thread
+#[cfg(target_arch = "wasm32")]
+fn host_cpu_cores() -> usize {
+    1
+}
+
 /// Complete benchmark report
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkReport {
+    /// Layout version (see `REPORT_SCHEMA_VERSION`); 0 for pre-versioning reports
+    #[serde(default)]
+    pub schema_version: u32,
     pub timestamp: String,
     pub target_frame_time_ms: f64,
     pub system_info: SystemInfo,
+    /// Every individual run, in execution order
     pub results: Vec<WorkloadResult>,
+    /// Per-workload mean ± std across repeated runs
+    #[serde(default)]
+    pub aggregated: Vec<AggregatedResult>,
+    /// The empty-world baseline slowed down during the suite (likely throttling)
+    #[serde(default)]
+    pub thermal_drift_detected: bool,
+    /// Largest baseline rise observed during the suite, in percent
+    #[serde(default)]
+    pub max_baseline_drift_percent: f64,
+    /// Median frame time of the Empty Baseline workload (engine overhead only)
+    #[serde(default)]
+    pub baseline_frame_time_ms: Option<f64>,
 }
 
 impl BenchmarkReport {
     pub fn new(target_ms: f64) -> Self {
         Self {
+            schema_version: REPORT_SCHEMA_VERSION,
             timestamp: chrono::Utc::now().to_rfc3339(),
             target_frame_time_ms: target_ms,
             system_info: SystemInfo::default(),
             results: Vec::new(),
+            aggregated: Vec::new(),
+            thermal_drift_detected: false,
+            max_baseline_drift_percent: 0.0,
+            baseline_frame_time_ms: None,
         }
     }
 
@@ -89,31 +596,228 @@ impl BenchmarkReport {
         self.results.push(result);
     }
 
-    /// Save report to JSON file
+    /// Total wall-clock time spent converging across every recorded run
+    pub fn total_duration_seconds(&self) -> f64 {
+        self.results.iter().map(|r| r.convergence_seconds).sum()
+    }
+
+    /// Group results by workload and variant (in first-seen order) and compute aggregates
+    pub fn aggregate(&mut self) {
+        let mut keys: Vec<(&str, Option<&str>)> = Vec::new();
+        for result in &self.results {
+            let key = (result.workload_name.as_str(), result.variant.as_deref());
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+
+        self.aggregated = keys
+            .iter()
+            .filter_map(|&(name, variant)| {
+                let runs: Vec<&WorkloadResult> = self
+                    .results
+                    .iter()
+                    .filter(|r| r.workload_name == name && r.variant.as_deref() == variant)
+                    .collect();
+                AggregatedResult::from_runs(&runs)
+            })
+            .collect();
+    }
+
+    /// Aggregated means when available (one per workload), otherwise every recorded run
+    pub fn summary_rows(&self) -> Vec<&WorkloadResult> {
+        if self.aggregated.is_empty() {
+            self.results.iter().collect()
+        } else {
+            self.aggregated.iter().map(|a| &a.mean).collect()
+        }
+    }
+
+    /// `summary_rows` ranked by throughput at breakdown, highest first.
+    ///
+    /// `results` stays in execution order for reproducibility; this is the
+    /// view used for display.
+    pub fn sorted_by_throughput(&self) -> Vec<&WorkloadResult> {
+        let mut rows = self.summary_rows();
+        rows.sort_by(|a, b| {
+            b.throughput_at_breakdown
+                .total_cmp(&a.throughput_at_breakdown)
+        });
+        rows
+    }
+
+    /// Pair up this report's workloads with `other`'s by display name.
+    ///
+    /// Rows follow this report's order, then workloads only `other` has.
+    /// If a report has several unaggregated runs of a workload, the last
+    /// one is used.
+    pub fn compare(&self, other: &BenchmarkReport) -> Vec<WorkloadComparison> {
+        let throughput_of = |report: &BenchmarkReport, name: &str| {
+            report
+                .summary_rows()
+                .into_iter()
+                .rev()
+                .find(|r| r.display_name() == name)
+                .map(|r| r.throughput_at_breakdown)
+        };
+
+        let mut names: Vec<String> = Vec::new();
+        for row in self.summary_rows().into_iter().chain(other.summary_rows()) {
+            let name = row.display_name();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        names
+            .into_iter()
+            .map(|name| WorkloadComparison {
+                throughput_a: throughput_of(self, &name),
+                throughput_b: throughput_of(other, &name),
+                name,
+            })
+            .collect()
+    }
+
+    /// Format an aligned ASCII table of breakdown points for terminal output.
+    ///
+    /// Uses the aggregated results when available (one row per workload),
+    /// otherwise one row per recorded run, ranked by throughput.
+    pub fn summary_table_string(&self) -> String {
+        let rows = self.sorted_by_throughput();
+
+        let name_width = rows
+            .iter()
+            .map(|r| r.display_name().len())
+            .max()
+            .unwrap_or(0)
+            .max("Workload".len());
+
+        let header = format!(
+            "{:>3} | {:<name_width$} | {:>10} | {:>12} | {:>20} | {:>12} | {:>10} | {:>6}",
+            "#", "Workload", "Breakdown", "Throughput", "Peak", "Work units/s", "Memory", "CV"
+        );
+        let separator = "-".repeat(header.len());
+
+        let mut table = format!("{}\n{}\n", header, separator);
+        for (rank, row) in rows.into_iter().enumerate() {
+            let noisy = if row.is_noisy() { " (noisy)" } else { "" };
+            let limit = if row.breakdown_found {
+                ""
+            } else {
+                " (exceeded max without breakdown)"
+            };
+            table.push_str(&format!(
+                "{:>3} | {:<name_width$} | {:>10} | {:>12} | {:>20} | {:>12} | {:>10} | {:>6.3}{}{}\n",
+                rank + 1,
+                row.display_name(),
+                row.breakdown_label(),
+                format_throughput(row.throughput_at_breakdown),
+                row.peak_label(),
+                format_throughput(row.work_units_per_sec),
+                format_bytes(row.total_bytes()),
+                row.stability_cv,
+                noisy,
+                limit,
+            ));
+        }
+        table.push_str(&format!(
+            "{}\nTotal duration: {}\n",
+            separator,
+            format_duration(self.total_duration_seconds())
+        ));
+        if let Some(baseline) = self.baseline_frame_time_ms {
+            table.push_str(&format!("Empty-world baseline: {:.3}ms\n", baseline));
+        }
+        table
+    }
+
+    /// Load a report from a JSON file (see `from_json`)
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+        Self::from_json(&json).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Parse a report, accepting older schema versions (fields added since
+    /// take their defaults) and rejecting newer ones
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        let report: Self =
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse report: {}", e))?;
+
+        if report.schema_version > REPORT_SCHEMA_VERSION {
+            return Err(format!(
+                "Unsupported report schema version {} (expected {} or older)",
+                report.schema_version, REPORT_SCHEMA_VERSION
+            ));
+        }
+        if report.schema_version < REPORT_SCHEMA_VERSION {
+            warn!(
+                "Report schema version {} is older than {}; newer fields are left empty",
+                report.schema_version, REPORT_SCHEMA_VERSION
+            );
+        }
+
+        Ok(report)
+    }
+
+    /// Serialize the report as pretty-printed JSON. This is how reports
+    /// leave a wasm32 build, where there is no filesystem to `save` to.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Failed to serialize: {}", e))
+    }
+
+    /// Save report to a JSON file in the results directory (see `results_dir`)
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn save(&self) -> Result<String, String> {
+        self.save_to(&crate::config::results_dir())
+    }
+
+    /// Save report to a timestamped JSON file in `dir`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save_to(&self, dir: &Path) -> Result<String, String> {
         // Ensure directory exists
-        let dir = Path::new(RESULTS_DIR);
         if !dir.exists() {
             fs::create_dir_all(dir).map_err(|e| format!("Failed to create directory: {}", e))?;
         }
 
         // Generate filename with timestamp
-        let filename = format!(
-            "{}/benchmark_{}.json",
-            RESULTS_DIR,
+        let path = dir.join(format!(
+            "benchmark_{}.json",
             chrono::Utc::now().format("%Y%m%d_%H%M%S")
-        );
+        ));
 
         // Serialize and write
-        let json = serde_json::to_string_pretty(self)
-            .map_err(|e| format!("Failed to serialize: {}", e))?;
+        let json = self.to_json()?;
 
-        fs::write(&filename, json).map_err(|e| format!("Failed to write file: {}", e))?;
+        write_atomic(&path, &json)?;
 
-        Ok(filename)
+        Ok(path.display().to_string())
     }
 }
 
+/// Write `contents` to `path` without ever leaving a partial file behind.
+///
+/// The data goes to a `.tmp` sibling first and is renamed into place only
+/// once fully written, so a process killed mid-write leaves at most a stray
+/// `.tmp` file rather than truncated output. Exporters should use this
+/// instead of `fs::write`.
+pub fn write_atomic(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), String> {
+    let tmp_path = path.with_extension(match path.extension() {
+        Some(ext) => format!("{}.tmp", ext.to_string_lossy()),
+        None => "tmp".to_string(),
+    });
+
+    fs::write(&tmp_path, contents).map_err(|e| format!("Failed to write file: {}", e))?;
+
+    fs::rename(&tmp_path, path).map_err(|e| {
+        let _ = fs::remove_file(&tmp_path);
+        format!("Failed to move file into place: {}", e)
+    })
+}
+
 /// Resource holding collected results
 #[derive(Resource, Default)]
 pub struct BenchmarkResults {
@@ -127,20 +831,29 @@ impl BenchmarkResults {
         self.current_workload_result = None;
     }
 
-    pub fn record_workload_result(
-        &mut self,
-        workload: SelectedWorkload,
-        breakdown_point: usize,
-        throughput: f64,
-        stats: SampleStats,
-    ) {
-        let result = WorkloadResult {
-            workload_name: workload.name().to_string(),
-            workload_description: workload.description().to_string(),
-            breakdown_point,
-            throughput_at_breakdown: throughput,
-            frame_time_stats: stats.into(),
-        };
+    /// Report for a run started by hand: the running suite's if there is
+    /// one, otherwise a fresh report, so a single-workload run is never
+    /// appended to the report an earlier suite left behind
+    pub fn ensure_report(&mut self, target_ms: f64, suite_running: bool) {
+        if !suite_running || self.report.is_none() {
+            self.start_new_report(target_ms);
+        }
+    }
+
+    /// Record a result; the Empty Baseline sets the report's baseline, and
+    /// results recorded after it get a net frame time
+    pub fn record_workload_result(&mut self, mut result: WorkloadResult) {
+        if let Some(ref mut report) = self.report {
+            let median = result.frame_time_stats.median_ms;
+            if result.workload_name == SelectedWorkload::EmptyBaseline.name() {
+                report.baseline_frame_time_ms = Some(median);
+            } else if let Some(baseline) = report.baseline_frame_time_ms {
+                result.net_frame_time_ms = Some(median - baseline);
+                if let Some(overhead) = result.frame_overhead_ms.as_mut() {
+                    *overhead -= baseline;
+                }
+            }
+        }
 
         self.current_workload_result = Some(result.clone());
 
@@ -149,6 +862,39 @@ impl BenchmarkResults {
         }
     }
 
+    /// Drop the most recent result, e.g. before re-running a noisy workload
+    pub fn discard_last_result(&mut self) {
+        self.current_workload_result = None;
+        if let Some(ref mut report) = self.report {
+            report.results.pop();
+        }
+    }
+
+    /// Record the drift monitor's outcome in the current report
+    pub fn record_thermal_drift(&mut self, detected: bool, max_drift_percent: f64) {
+        if let Some(ref mut report) = self.report {
+            report.thermal_drift_detected = detected;
+            report.max_baseline_drift_percent = max_drift_percent;
+        }
+    }
+
+    /// Compute per-workload aggregates for the current report
+    pub fn finalize_report(&mut self) {
+        if let Some(ref mut report) = self.report {
+            report.aggregate();
+        }
+    }
+
+    /// Summary table of the current report (empty if there is none)
+    pub fn summary_table_string(&self) -> String {
+        self.report
+            .as_ref()
+            .map(|r| r.summary_table_string())
+            .unwrap_or_default()
+    }
+
+    /// Save the current report, returning the file path
+    #[cfg(not(target_arch = "wasm32"))]
     pub fn save_report(&self) -> Result<String, String> {
         match &self.report {
             Some(report) => report.save(),
@@ -156,6 +902,13 @@ impl BenchmarkResults {
         }
     }
 
+    /// There is no filesystem on wasm32; the host page takes the report
+    /// from `BenchmarkReport::to_json` instead
+    #[cfg(target_arch = "wasm32")]
+    pub fn save_report(&self) -> Result<String, String> {
+        Err("Saving reports is not supported on wasm32; use BenchmarkReport::to_json".to_string())
+    }
+
     pub fn has_results(&self) -> bool {
         self.report.as_ref().map(|r| !r.results.is_empty()).unwrap_or(false)
     }
@@ -176,3 +929,71 @@ pub struct BenchmarkComplete {
 /// Event requesting results to be saved
 #[derive(Event, Message)]
 pub struct SaveResultsRequest;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A report in the version 1 layout, with only the fields it required
+    const V1_REPORT: &str = r#"{
+        "schema_version": 1,
+        "timestamp": "2025-01-15T10:30:00Z",
+        "target_frame_time_ms": 16.666,
+        "system_info": { "os": "linux", "cpu_cores": 8, "bevy_version": "0.17.3" },
+        "results": [{
+            "workload_name": "Position/Velocity Update",
+            "workload_description": "",
+            "breakdown_point": 2500000,
+            "throughput_at_breakdown": 150000000.0,
+            "target_frame_time_ms": 16.666,
+            "structural_ops_per_sec": 0.0,
+            "archetype_count": 1,
+            "frame_time_stats": {
+                "min_ms": 15.0, "max_ms": 18.0, "median_ms": 16.6, "mean_ms": 16.6,
+                "std_dev_ms": 0.4, "p95_ms": 17.2, "p99_ms": 17.8
+            }
+        }]
+    }"#;
+
+    #[test]
+    fn single_runs_start_a_fresh_report_unless_a_suite_is_running() {
+        let mut results = BenchmarkResults::default();
+        results.start_new_report(10.0);
+
+        // A leftover suite report is replaced
+        results.ensure_report(20.0, false);
+        let report = results.report.as_ref().unwrap();
+        assert_eq!(report.target_frame_time_ms, 20.0);
+
+        // A running suite keeps its report
+        results.ensure_report(30.0, true);
+        let report = results.report.as_ref().unwrap();
+        assert_eq!(report.target_frame_time_ms, 20.0);
+    }
+
+    #[test]
+    fn older_schema_versions_still_load() {
+        let report = BenchmarkReport::from_json(V1_REPORT).unwrap();
+        assert_eq!(report.schema_version, 1);
+        assert_eq!(report.results.len(), 1);
+        assert_eq!(report.results[0].breakdown_point, 2_500_000);
+        assert_eq!(report.results[0].knee_count, None);
+    }
+
+    #[test]
+    fn newer_schema_versions_are_rejected() {
+        let json = V1_REPORT.replacen(
+            "\"schema_version\": 1",
+            &format!("\"schema_version\": {}", REPORT_SCHEMA_VERSION + 1),
+            1,
+        );
+        assert!(BenchmarkReport::from_json(&json).is_err());
+    }
+
+    #[test]
+    fn current_reports_round_trip() {
+        let report = BenchmarkReport::new(16.666);
+        let loaded = BenchmarkReport::from_json(&report.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.schema_version, REPORT_SCHEMA_VERSION);
+    }
+}
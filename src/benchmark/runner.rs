@@ -1,23 +1,64 @@
 //! Benchmark execution and control logic.
 
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+
 use bevy::ecs::message::Message;
+use bevy::ecs::system::ScheduleSystem;
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
+use serde::Serialize;
 
-use crate::benchmark::results::{BenchmarkComplete, BenchmarkResults};
-use crate::components::BenchmarkEntity;
+use crate::benchmark::results::{BenchmarkComplete, BenchmarkResults, WorkloadResult};
+use crate::benchmark::workloads::{
+    BenchmarkSet, BulkDespawnState, ComponentToggleState, DefragRecoveryState, DisjointOverlap,
+    IterationSink, SpawnDespawnState, WorkloadFootprints, WorkloadWeights,
+};
+use crate::components::{BenchmarkEntity, FastRng};
 use crate::config::{
-    GROWTH_MULTIPLIER, MAX_ENTITY_COUNT, MIN_CONVERGENCE_GAP, MIN_ENTITY_COUNT, SAMPLE_FRAMES,
-    TARGET_FRAME_TIME_MS, WARMUP_FRAMES,
+    results_dir, BenchmarkConfig, DEFAULT_PERCENTILES, EARLY_ABORT_MIN_FRAMES,
+    EARLY_ABORT_MULTIPLIER, GROWTH_MULTIPLIER, INITIAL_ENTITY_COUNT, MAX_ENTITY_COUNT,
+    MAX_WARMUP_FRAMES, MIN_CONVERGENCE_GAP, MIN_ENTITY_COUNT, SAMPLE_FRAMES, SAMPLE_SUB_WINDOWS,
+    SPAWN_VALIDATION_FRAME, TRACE_LOG_FILE, WARMUP_CV_THRESHOLD, WARMUP_FRAMES,
+    WARMUP_STABILITY_WINDOW,
+};
+use crate::metrics::{
+    format_throughput, probe_parallelism, update_metrics_snapshot, update_world_stats,
+    FrameMetrics, ParIterProbe, ParallelismStats, SampleStats, SharedMetrics, WorldStats,
 };
-use crate::metrics::FrameMetrics;
 use crate::state::{AppState, BenchmarkPhase, BenchmarkState, SelectedWorkload};
 
-/// Plugin for benchmark execution systems
+/// Plugin for benchmark execution systems.
+///
+/// Self-contained: initializes the states and resources it needs, so it can
+/// run without `BenchmarkUiPlugin` or the keyboard input handling.
 pub struct BenchmarkRunnerPlugin;
 
 impl Plugin for BenchmarkRunnerPlugin {
     fn build(&self, app: &mut App) {
-        app.add_message::<BenchmarkComplete>()
+        app.init_resource::<BenchmarkConfig>();
+        app.world_mut().resource_mut::<BenchmarkConfig>().validate();
+        let history_length = app
+            .world()
+            .resource::<BenchmarkConfig>()
+            .frame_history_length;
+        let metrics_port = app.world().resource::<BenchmarkConfig>().metrics_port;
+        let metrics_bind_all = app.world().resource::<BenchmarkConfig>().metrics_bind_all;
+
+        app.init_state::<AppState>()
+            .init_state::<BenchmarkPhase>()
+            .init_resource::<SelectedWorkload>()
+            .init_resource::<BenchmarkState>()
+            .insert_resource(FrameMetrics::with_history_length(history_length))
+            .init_resource::<BenchmarkResults>()
+            .init_resource::<WorldStats>()
+            .init_resource::<ParallelismStats>()
+            .init_resource::<ParIterProbe>()
+            .init_resource::<PendingRespawn>()
+            .init_resource::<StepRequested>()
+            .init_resource::<WorkloadWeights>()
+            .init_resource::<WorkloadFootprints>()
+            .add_message::<BenchmarkComplete>()
             .add_message::<SpawnEntitiesRequest>()
             .add_message::<DespawnAllRequest>()
             .add_systems(
@@ -25,16 +66,61 @@ impl Plugin for BenchmarkRunnerPlugin {
                 (
                     manage_benchmark_phase,
                     handle_phase_transitions,
+                    validate_spawn_count.run_if(in_state(BenchmarkPhase::WarmUp)),
+                    arm_par_iter_probe.run_if(in_state(BenchmarkPhase::WarmUp)),
                     collect_samples.run_if(in_state(BenchmarkPhase::Sampling)),
                     adjust_entity_count.run_if(in_state(BenchmarkPhase::Adjusting)),
                 )
                     .chain()
                     .run_if(in_state(AppState::Running)),
             )
+            // After Process so the despawn also sees entities a churn
+            // workload spawned earlier in the frame
+            .add_systems(
+                Update,
+                (handle_spawn_requests, handle_despawn_requests)
+                    .chain()
+                    .after(BenchmarkSet::Process),
+            )
+            // Nothing spawns or processes during the suite's cooldown
+            .configure_sets(
+                Update,
+                BenchmarkSet::Spawn.run_if(not(in_state(BenchmarkPhase::Cooldown))),
+            )
+            // Churn workloads respawn every frame, so the world would never
+            // empty for a pending respawn while they keep running
+            .configure_sets(
+                Update,
+                BenchmarkSet::Process
+                    .run_if(not(in_state(BenchmarkPhase::Cooldown)))
+                    .run_if(no_pending_respawn),
+            )
+            .add_systems(
+                Update,
+                dispatch_pending_respawn
+                    .before(BenchmarkSet::Spawn)
+                    .run_if(not(in_state(BenchmarkPhase::Cooldown))),
+            )
+            .add_systems(Update, update_world_stats)
+            .add_systems(Update, collect_par_iter_probe.after(BenchmarkSet::Process))
             .add_systems(
                 Update,
-                (handle_spawn_requests, handle_despawn_requests).chain(),
+                probe_parallelism.run_if(
+                    not(in_state(BenchmarkPhase::WarmUp))
+                        .and(not(in_state(BenchmarkPhase::Sampling))),
+                ),
             );
+
+        if let Some(port) = metrics_port {
+            match SharedMetrics::serve(port, metrics_bind_all) {
+                Ok(shared) => {
+                    info!("Serving Prometheus metrics on port {}", port);
+                    app.insert_resource(shared)
+                        .add_systems(Update, update_metrics_snapshot);
+                }
+                Err(e) => warn!("Failed to start metrics server on port {}: {}", port, e),
+            }
+        }
     }
 }
 
@@ -48,21 +134,160 @@ pub struct SpawnEntitiesRequest {
 #[derive(Event, Message)]
 pub struct DespawnAllRequest;
 
+/// Spawn request held back until the previous entities are gone.
+///
+/// Despawns only take effect when commands are applied, so a
+/// `SpawnEntitiesRequest` written in the same frame as a `DespawnAllRequest`
+/// could be counted alongside stale entities. Respawns write the despawn
+/// request and `request` the new count here instead;
+/// `dispatch_pending_respawn` sends it once no `BenchmarkEntity` remains.
+#[derive(Resource, Default, Debug)]
+pub struct PendingRespawn(Option<usize>);
+
+impl PendingRespawn {
+    /// Spawn `count` entities once the world is empty of benchmark entities
+    pub fn request(&mut self, count: usize) {
+        self.0 = Some(count);
+    }
+
+    /// Drop any respawn that hasn't been dispatched yet
+    pub fn cancel(&mut self) {
+        self.0 = None;
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+/// Manual phase stepping for `BenchmarkConfig::step_mode`.
+///
+/// With step mode on, warm-up and sampling finish their work but hold the
+/// transition out of the phase until a step is requested (the `N` key). A
+/// step requested early is used at the next phase boundary.
+#[derive(Resource, Default, Debug)]
+pub struct StepRequested {
+    requested: bool,
+    /// The current hold has already been logged
+    announced: bool,
+}
+
+impl StepRequested {
+    /// Let the next held phase transition through
+    pub fn request(&mut self) {
+        self.requested = true;
+    }
+
+    /// Drop a step that hasn't been used yet
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Whether `phase` may end now: always outside step mode, otherwise only
+    /// by using up a requested step
+    fn allow(&mut self, step_mode: bool, phase: BenchmarkPhase) -> bool {
+        if !step_mode || self.requested {
+            self.clear();
+            return true;
+        }
+        if !self.announced {
+            info!("Step mode: {:?} finished, press N to advance", phase);
+            self.announced = true;
+        }
+        false
+    }
+}
+
+/// Run condition: no respawn is waiting for the old entities to go
+fn no_pending_respawn(pending: Res<PendingRespawn>) -> bool {
+    !pending.is_pending()
+}
+
+/// Send the pending respawn once every old `BenchmarkEntity` has been despawned
+fn dispatch_pending_respawn(
+    mut pending: ResMut<PendingRespawn>,
+    query: Query<(), With<BenchmarkEntity>>,
+    mut spawn_events: MessageWriter<SpawnEntitiesRequest>,
+) {
+    let Some(count) = pending.0 else {
+        return;
+    };
+    if !query.is_empty() {
+        return;
+    }
+
+    pending.cancel();
+    spawn_events.write(SpawnEntitiesRequest { count });
+}
+
 /// Manages the benchmark phase state machine
 fn manage_benchmark_phase(
     phase: Res<State<BenchmarkPhase>>,
     mut next_phase: ResMut<NextState<BenchmarkPhase>>,
     mut state: ResMut<BenchmarkState>,
-    _metrics: Res<FrameMetrics>,
+    metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    pending: Res<PendingRespawn>,
+    mut step: ResMut<StepRequested>,
+    workload: Res<SelectedWorkload>,
 ) {
     match phase.get() {
         BenchmarkPhase::Idle => {
             // Transition to warmup handled by start command
         }
         BenchmarkPhase::WarmUp => {
+            // Warm-up starts counting once the new entities have been requested
+            if pending.is_pending() {
+                return;
+            }
             state.frame_counter += 1;
-            if state.frame_counter >= WARMUP_FRAMES {
+
+            match state.cache_warm_remaining {
+                // Cache-warming frames are ordinary frames: the process
+                // systems run once each, paging the component data back in
+                Some(remaining) if remaining > 0 => {
+                    state.cache_warm_remaining = Some(remaining - 1);
+                    return;
+                }
+                Some(_) => {}
+                None => {
+                    let done = if config.adaptive_warmup {
+                        let stable = metrics
+                            .trailing_cv(WARMUP_STABILITY_WINDOW, BenchmarkPhase::WarmUp)
+                            .is_some_and(|cv| cv < WARMUP_CV_THRESHOLD);
+                        stable || state.frame_counter >= MAX_WARMUP_FRAMES
+                    } else {
+                        state.frame_counter >= WARMUP_FRAMES
+                    };
+                    if !done {
+                        return;
+                    }
+
+                    if config.adaptive_warmup {
+                        info!(
+                            "Warm-up {} after {} frames",
+                            if state.frame_counter >= MAX_WARMUP_FRAMES {
+                                "hit the frame cap"
+                            } else {
+                                "stabilized"
+                            },
+                            state.frame_counter
+                        );
+                    }
+                    let passes = cache_warm_passes(&config, *workload);
+                    if passes > 0 {
+                        info!("Warming caches with {} extra passes", passes);
+                        state.cache_warm_remaining = Some(passes);
+                        return;
+                    }
+                    state.cache_warm_remaining = Some(0);
+                }
+            }
+
+            if step.allow(config.step_mode, BenchmarkPhase::WarmUp) {
+                state.warmup_frames_total += state.frame_counter;
                 state.frame_counter = 0;
+                state.cache_warm_remaining = None;
                 next_phase.set(BenchmarkPhase::Sampling);
             }
         }
@@ -75,25 +300,99 @@ fn manage_benchmark_phase(
         BenchmarkPhase::Complete => {
             // Stay complete until reset
         }
+        BenchmarkPhase::Cooldown => {
+            // The previous workload's despawn is applied in the first frame;
+            // the rest let its deferred work settle with nothing running
+            state.frame_counter += 1;
+            if state.frame_counter >= config.cooldown_frames {
+                next_phase.set(BenchmarkPhase::WarmUp);
+            }
+        }
     }
 }
 
+/// Cache-warming passes `workload` gets at the end of each warm-up
+fn cache_warm_passes(config: &BenchmarkConfig, workload: SelectedWorkload) -> usize {
+    if workload.warms_cache() {
+        config.cache_warm_passes
+    } else {
+        0
+    }
+}
+
+/// One line of the `--trace-log` phase transition log
+#[derive(Debug, Serialize)]
+struct PhaseTraceEntry {
+    timestamp: String,
+    workload: &'static str,
+    from: Option<String>,
+    to: Option<String>,
+    entity_count: usize,
+    /// Median of the current sample window (0.0 if empty)
+    median_ms: f64,
+}
+
+/// Append one JSON line to `TRACE_LOG_FILE` in the results directory
+fn append_trace_entry(entry: &PhaseTraceEntry) -> Result<(), String> {
+    let dir = results_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    let line = serde_json::to_string(entry).map_err(|e| format!("Failed to serialize: {}", e))?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(TRACE_LOG_FILE))
+        .map_err(|e| format!("Failed to open trace log: {}", e))?;
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write trace log: {}", e))
+}
+
 /// Handle transitions between phases
 fn handle_phase_transitions(
     mut phase_events: MessageReader<StateTransitionEvent<BenchmarkPhase>>,
     mut metrics: ResMut<FrameMetrics>,
     mut state: ResMut<BenchmarkState>,
+    config: Res<BenchmarkConfig>,
+    (mut pending, mut step): (ResMut<PendingRespawn>, ResMut<StepRequested>),
+    workload: Res<SelectedWorkload>,
+    mut probe: ResMut<ParIterProbe>,
 ) {
     for event in phase_events.read() {
+        // Logged before the arms below clear the samples
+        if config.trace_log {
+            let entry = PhaseTraceEntry {
+                timestamp: chrono::Utc::now().to_rfc3339(),
+                workload: workload.name(),
+                from: event.exited.map(|phase| format!("{:?}", phase)),
+                to: event.entered.map(|phase| format!("{:?}", phase)),
+                entity_count: state.entity_count,
+                median_ms: metrics.running_median(),
+            };
+            if let Err(e) = append_trace_entry(&entry) {
+                warn!("Failed to record phase transition: {}", e);
+            }
+        }
+
         match event.entered {
             Some(BenchmarkPhase::WarmUp) => {
-                info!("Entering warm-up phase ({} frames)", WARMUP_FRAMES);
+                if config.adaptive_warmup {
+                    info!(
+                        "Entering warm-up phase (adaptive, up to {} frames)",
+                        MAX_WARMUP_FRAMES
+                    );
+                } else {
+                    info!("Entering warm-up phase ({} frames)", WARMUP_FRAMES);
+                }
                 state.frame_counter = 0;
+                // Only the first warm-up of a workload starts the clock
+                state.workload_started_at.get_or_insert_with(Instant::now);
             }
             Some(BenchmarkPhase::Sampling) => {
-                info!("Entering sampling phase ({} frames)", SAMPLE_FRAMES);
+                info!("Entering sampling phase ({} frames)", config.sample_frames);
                 metrics.clear_samples();
                 state.frame_counter = 0;
+                // Workloads without instrumented systems never take the
+                // probe; keep it from counting sampled frames later
+                probe.disarm();
             }
             Some(BenchmarkPhase::Adjusting) => {
                 info!("Adjusting entity count based on samples");
@@ -101,28 +400,208 @@ fn handle_phase_transitions(
             Some(BenchmarkPhase::Complete) => {
                 info!("Benchmark complete!");
             }
+            Some(BenchmarkPhase::Cooldown) => {
+                info!("Cooling down ({} idle frames)", config.cooldown_frames);
+                state.frame_counter = 0;
+            }
             Some(BenchmarkPhase::Idle) => {
                 info!("Benchmark idle");
+                // A stopped run must not respawn entities later
+                pending.cancel();
+                step.clear();
             }
             None => {}
         }
     }
 }
 
+/// Check that the workload spawned as many entities as were requested.
+///
+/// Runs once per warm-up, on `SPAWN_VALIDATION_FRAME`, after the spawn
+/// commands have been applied. A mismatch is logged and flagged on the
+/// result, since the breakdown point would otherwise be mislabeled.
+fn validate_spawn_count(
+    mut state: ResMut<BenchmarkState>,
+    workload: Res<SelectedWorkload>,
+    query: Query<(), With<BenchmarkEntity>>,
+) {
+    if state.frame_counter != SPAWN_VALIDATION_FRAME || !workload.spawns_requested_count() {
+        return;
+    }
+
+    let actual = query.iter().count();
+    state.spawned_count = actual;
+
+    if actual != state.entity_count {
+        warn!(
+            "{}: requested {} entities but {} were spawned",
+            workload.name(),
+            state.entity_count,
+            actual
+        );
+        state.spawn_mismatch = true;
+    }
+}
+
+/// Instrument one warm-up frame per search iteration.
+///
+/// Armed on `SPAWN_VALIDATION_FRAME` so the recorded frame has the full
+/// entity count and the counters never touch the sampled frames.
+fn arm_par_iter_probe(state: Res<BenchmarkState>, mut probe: ResMut<ParIterProbe>) {
+    if state.frame_counter == SPAWN_VALIDATION_FRAME {
+        probe.arm();
+    }
+}
+
+/// Log how the armed frame's `par_iter_mut` work was split across threads.
+///
+/// Stays armed until an instrumented system actually runs, so the ordering
+/// against `arm_par_iter_probe` within a frame doesn't matter.
+fn collect_par_iter_probe(mut probe: ResMut<ParIterProbe>, mut state: ResMut<BenchmarkState>) {
+    let Some(sample) = probe.take() else {
+        return;
+    };
+
+    let total: usize = sample.per_thread.iter().sum();
+    let shares: Vec<String> = sample
+        .per_thread
+        .iter()
+        .map(|&count| format!("{:.0}%", count as f64 / total.max(1) as f64 * 100.0))
+        .collect();
+    info!(
+        "par_iter at {} entities: {} tasks, {} items on {} threads [{}]",
+        state.entity_count,
+        sample.tasks,
+        total,
+        sample.per_thread.len(),
+        shares.join(", ")
+    );
+    state.par_iter_tasks = Some(sample.tasks);
+}
+
 /// Collect frame time samples during sampling phase
 fn collect_samples(
     time: Res<Time>,
+    config: Res<BenchmarkConfig>,
     mut metrics: ResMut<FrameMetrics>,
     mut state: ResMut<BenchmarkState>,
     mut next_phase: ResMut<NextState<BenchmarkPhase>>,
+    workload: Res<SelectedWorkload>,
+    mut step: ResMut<StepRequested>,
 ) {
-    metrics.add_sample(time.delta_secs_f64());
-    state.frame_counter += 1;
+    // A full window held by step mode takes no more samples
+    if state.frame_counter < config.sample_frames {
+        let secs = metrics.measured_secs(&time, config.timing);
+        metrics.add_sample(secs);
+        metrics.add_window_time(time.delta_secs_f64());
+        state.frame_counter += 1;
+    }
+
+    if state.frame_counter >= config.sample_frames {
+        if step.allow(config.step_mode, BenchmarkPhase::Sampling) {
+            state.frame_counter = 0;
+            next_phase.set(BenchmarkPhase::Adjusting);
+        }
+        return;
+    }
+
+    // Bail out early when badly overshooting so the search stays responsive
+    if state.frame_counter >= EARLY_ABORT_MIN_FRAMES {
+        let limit = config.target_frame_time_ms(*workload) * EARLY_ABORT_MULTIPLIER;
+        let median = metrics.running_median();
+        // Step mode ignores the early abort and samples the full window
+        if median > limit && !config.step_mode {
+            info!(
+                "Median {:.2}ms exceeds {:.2}ms after {} frames, ending sampling early",
+                median, limit, state.frame_counter
+            );
+            state.frame_counter = 0;
+            next_phase.set(BenchmarkPhase::Adjusting);
+        }
+    }
+}
+
+/// Outcome of one step of the breakdown point search
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchDecision {
+    /// Run the next measurement at `count` entities
+    Next { count: usize },
+    /// The search has converged on `breakdown` entities
+    Converged { breakdown: usize },
+    /// `count` is the entity limit and still stayed under target: there is
+    /// no breakdown point to report
+    LimitReached { count: usize },
+}
+
+/// Decide the next step of the breakdown point search.
+///
+/// `low` and `high` are the search bounds after recording whether `current`
+/// exceeded the target; `at_max` means no count has exceeded it yet, so
+/// the search is still growing exponentially rather than bisecting.
+/// Converges once the bounds are within 2% of `current` or
+/// `MIN_CONVERGENCE_GAP` entities of each other.
+pub fn next_entity_count(
+    current: usize,
+    low: usize,
+    high: usize,
+    exceeds: bool,
+    at_max: bool,
+) -> SearchDecision {
+    let gap = high.saturating_sub(low);
+    let relative_gap = gap as f64 / current.max(1) as f64;
+
+    if relative_gap < 0.02 || gap < MIN_CONVERGENCE_GAP {
+        // The breakdown point is the last count that stayed under target
+        let breakdown = if exceeds { low } else { current };
+        return SearchDecision::Converged { breakdown };
+    }
+
+    let count = if !exceeds && at_max {
+        // Still in exponential growth phase
+        grown_entity_count(current)
+    } else {
+        // Binary search phase
+        low.saturating_add(gap / 2)
+    }
+    .clamp(MIN_ENTITY_COUNT, MAX_ENTITY_COUNT);
+
+    SearchDecision::Next { count }
+}
 
-    if state.frame_counter >= SAMPLE_FRAMES {
-        state.frame_counter = 0;
-        next_phase.set(BenchmarkPhase::Adjusting);
+/// Hold a search step to the workload's entity limit `cap`.
+///
+/// The search stops growing at the limit; measuring at the limit and still
+/// staying under target means no breakdown exists in range, rather than a
+/// breakdown at the limit.
+pub fn cap_decision(
+    decision: SearchDecision,
+    current: usize,
+    exceeds: bool,
+    cap: usize,
+) -> SearchDecision {
+    match decision {
+        SearchDecision::Next { count } if count >= cap => {
+            if !exceeds && current >= cap {
+                SearchDecision::LimitReached { count: current }
+            } else {
+                SearchDecision::Next { count: cap }
+            }
+        }
+        decision => decision,
+    }
+}
+
+/// `current` multiplied by `GROWTH_MULTIPLIER`, saturating at `MAX_ENTITY_COUNT`.
+///
+/// The product is bounds-checked while still an `f64`: near the clamp it
+/// can round past `usize::MAX`, and a count that comes back smaller than
+/// `current` would send the search back down instead of up.
+pub fn grown_entity_count(current: usize) -> usize {
+    let grown = current as f64 * GROWTH_MULTIPLIER;
+    if !grown.is_finite() || grown >= MAX_ENTITY_COUNT as f64 {
+        return MAX_ENTITY_COUNT;
     }
+    (grown as usize).max(current).min(MAX_ENTITY_COUNT)
 }
 
 /// Adjust entity count based on collected samples using binary search
@@ -130,23 +609,59 @@ fn adjust_entity_count(
     mut state: ResMut<BenchmarkState>,
     metrics: Res<FrameMetrics>,
     mut next_phase: ResMut<NextState<BenchmarkPhase>>,
-    mut spawn_events: MessageWriter<SpawnEntitiesRequest>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut pending: ResMut<PendingRespawn>,
     mut despawn_events: MessageWriter<DespawnAllRequest>,
     mut complete_events: MessageWriter<BenchmarkComplete>,
     workload: Res<SelectedWorkload>,
     mut results: ResMut<BenchmarkResults>,
+    config: Res<BenchmarkConfig>,
+    world_stats: Res<WorldStats>,
+    (weights, footprints): (Res<WorkloadWeights>, Res<WorkloadFootprints>),
+    overlap: Option<Res<DisjointOverlap>>,
+    defrag: Option<Res<DefragRecoveryState>>,
+    bulk_despawn: Option<Res<BulkDespawnState>>,
+    spawn_despawn: Option<Res<SpawnDespawnState>>,
 ) {
-    let stats = metrics.sample_stats();
-    let exceeds_target = stats.median_exceeds(TARGET_FRAME_TIME_MS);
+    let stats = metrics.sample_stats(&config.percentiles, config.sample_sub_windows);
+
+    // No samples means a 0ms median, which would read as "under target" and
+    // grow the entity count without bound. There is no result to record, so
+    // stop the run (and any automated suite, which would otherwise wait
+    // forever for a completion) and return to the menu
+    if stats.count == 0 {
+        warn!(
+            "No frame samples collected at {} entities ({} non-finite dropped); aborting search",
+            state.entity_count, stats.dropped
+        );
+        state.cancel_suite();
+        despawn_events.write(DespawnAllRequest);
+        next_phase.set(BenchmarkPhase::Idle);
+        next_app_state.set(AppState::Menu);
+        return;
+    }
+
+    let target_ms = config.target_frame_time_ms(*workload);
+    let exceeds_target = stats.median_exceeds(target_ms);
 
     info!(
-        "Entity count: {} | Median frame time: {:.2}ms | Target: {:.2}ms | {}",
+        "Entity count: {} | Median frame time: {:.2}ms (sub-windows {:.2?}) | Target: {:.2}ms | {}",
         state.entity_count,
-        stats.median,
-        TARGET_FRAME_TIME_MS,
+        stats.decision_median(),
+        stats.sub_window_medians,
+        target_ms,
         if exceeds_target { "OVER" } else { "UNDER" }
     );
 
+    // Every measured count is a point on the throughput curve; keep the
+    // highest for the knee (fixed-cost workloads have no curve)
+    if !workload.is_fixed_cost() && stats.median > 0.0 {
+        let throughput = state.entity_count as f64 * (1000.0 / stats.median);
+        if state.knee.is_none_or(|(_, best)| throughput > best) {
+            state.knee = Some((state.entity_count, throughput));
+        }
+    }
+
     // Binary search logic
     if exceeds_target {
         // We're over the target, need fewer entities
@@ -156,25 +671,138 @@ fn adjust_entity_count(
         state.search_low = state.entity_count;
     }
 
-    // Check if we've converged (within 2% or absolute minimum gap)
-    let gap = state.search_high - state.search_low;
-    let relative_gap = gap as f64 / state.entity_count as f64;
+    // Nothing scales with the entity count in fixed-cost workloads, so a
+    // single measurement is the result
+    let decision = if workload.is_fixed_cost() {
+        SearchDecision::Converged { breakdown: 0 }
+    } else {
+        next_entity_count(
+            state.entity_count,
+            state.search_low,
+            state.search_high,
+            exceeds_target,
+            state.search_high == MAX_ENTITY_COUNT,
+        )
+    };
+
+    let decision = cap_decision(
+        decision,
+        state.entity_count,
+        exceeds_target,
+        workload.max_entity_count(),
+    );
 
-    if relative_gap < 0.02 || gap < MIN_CONVERGENCE_GAP {
-        // We've found the breakdown point
-        let breakdown = if exceeds_target {
-            state.search_low
+    let converged = match decision {
+        SearchDecision::Converged { breakdown } => {
+            info!("Breakdown point found: {} entities", breakdown);
+            Some((breakdown, true))
+        }
+        SearchDecision::LimitReached { count } => {
+            warn!(
+                "{}: still under target at the {} entity limit, no breakdown found",
+                workload.name(),
+                count
+            );
+            Some((count, false))
+        }
+        SearchDecision::Next { .. } => None,
+    };
+
+    if let Some((breakdown, breakdown_found)) = converged {
+        // Calculate throughput at breakdown (a 0ms median would divide by zero)
+        let throughput = if stats.median > 0.0 {
+            breakdown as f64 * (1000.0 / stats.median)
         } else {
-            state.entity_count
+            0.0
         };
 
-        info!("Breakdown point found: {} entities", breakdown);
+        if stats.dropped > 0 {
+            warn!(
+                "{}: dropped {} non-finite frame samples during the search",
+                workload.name(),
+                stats.dropped
+            );
+        }
 
-        // Calculate throughput at breakdown
-        let throughput = breakdown as f64 * (1000.0 / stats.median);
+        let worst_spike = metrics.worst_sample();
+
+        if let Some((count, knee_throughput)) = state.knee {
+            info!(
+                "Throughput knee: {} entities ({})",
+                count,
+                format_throughput(knee_throughput)
+            );
+        }
 
         // Record results
-        results.record_workload_result(*workload, breakdown, throughput, stats);
+        results.record_workload_result(WorkloadResult {
+            structural_ops_per_sec: metrics.window_structural_ops_per_sec(),
+            archetype_count: world_stats.archetype_count,
+            populated_archetype_count: world_stats.populated_archetype_count,
+            convergence_seconds: state
+                .workload_started_at
+                .map(|start| start.elapsed().as_secs_f64())
+                .unwrap_or(0.0),
+            spawned_count: state.spawned_count,
+            warmup_frames: state.warmup_frames_total,
+            spawn_mismatch: state.spawn_mismatch,
+            work_weight: weights.configured_weight(*workload, &config),
+            work_units_per_sec: throughput * weights.configured_weight(*workload, &config),
+            spawn_chunk_size: if *workload == SelectedWorkload::ChunkedSpawn {
+                config.spawn_chunk_size
+            } else {
+                0
+            },
+            variant: config.variant_name(*workload),
+            breakdown_found,
+            par_iter_tasks: state.par_iter_tasks,
+            cache_warm_passes: cache_warm_passes(&config, *workload),
+            bytes_per_entity: footprints.bytes_per_entity(*workload),
+            attempts: state.retries + 1,
+            knee_count: state.knee.map(|(count, _)| count),
+            knee_throughput: state.knee.map(|(_, throughput)| throughput),
+            rng_backend: config.rng_backend.name().to_string(),
+            toggle_fraction: (*workload == SelectedWorkload::ComponentAddRemove)
+                .then_some(config.toggle_fraction),
+            toggle_period: (*workload == SelectedWorkload::ComponentAddRemove)
+                .then_some(config.toggle_period),
+            toggle_max: config
+                .toggle_max
+                .filter(|_| *workload == SelectedWorkload::ComponentAddRemove),
+            worst_spike_ms: worst_spike.map_or(0.0, |(_, ms)| ms),
+            worst_spike_frame: worst_spike.map_or(0, |(frame, _)| frame),
+            black_box_reads: workload
+                .uses_black_box_toggle()
+                .then_some(config.black_box_reads),
+            fragmented_iteration_ms: defrag
+                .as_ref()
+                .filter(|_| *workload == SelectedWorkload::DefragRecovery)
+                .and_then(|defrag| defrag.fragmented_median_ms()),
+            recovered_iteration_ms: defrag
+                .as_ref()
+                .filter(|_| *workload == SelectedWorkload::DefragRecovery)
+                .and_then(|defrag| defrag.recovered_median_ms()),
+            teardown_ms: bulk_despawn
+                .as_ref()
+                .filter(|_| *workload == SelectedWorkload::BulkDespawn)
+                .and_then(|bulk| bulk.mean_teardown_ms()),
+            // Made net of the baseline when the result is recorded
+            frame_overhead_ms: (workload.is_fixed_cost()
+                && *workload != SelectedWorkload::EmptyBaseline)
+                .then_some(stats.median),
+            parallel_overlap: if *workload == SelectedWorkload::DisjointParallel {
+                overlap.and_then(|overlap| overlap.fraction())
+            } else {
+                None
+            },
+            // The rate the churn setup captured, which a later [ or ] can't change
+            churn_rate: if workload.uses_churn_rate() {
+                spawn_despawn.map_or(config.churn_rate, |state| state.churn_rate)
+            } else {
+                0.0
+            },
+            ..WorkloadResult::new(*workload, breakdown, throughput, target_ms, stats)
+        });
 
         // Signal completion
         complete_events.write(BenchmarkComplete {
@@ -187,20 +815,14 @@ fn adjust_entity_count(
         return;
     }
 
-    // Calculate next entity count
-    let next_count = if !exceeds_target && state.search_high == MAX_ENTITY_COUNT {
-        // Still in exponential growth phase
-        ((state.entity_count as f64) * GROWTH_MULTIPLIER) as usize
-    } else {
-        // Binary search phase
-        (state.search_low + state.search_high) / 2
-    }
-    .clamp(MIN_ENTITY_COUNT, MAX_ENTITY_COUNT);
+    let SearchDecision::Next { count: next_count } = decision else {
+        return;
+    };
 
-    // Despawn all and spawn new count
+    // Despawn all, then spawn the new count once the old entities are gone
     despawn_events.write(DespawnAllRequest);
     state.entity_count = next_count;
-    spawn_events.write(SpawnEntitiesRequest { count: next_count });
+    pending.request(next_count);
 
     // Go back to warmup
     next_phase.set(BenchmarkPhase::WarmUp);
@@ -221,11 +843,16 @@ fn handle_despawn_requests(
     mut commands: Commands,
     mut events: MessageReader<DespawnAllRequest>,
     query: Query<Entity, With<BenchmarkEntity>>,
+    roots: Query<Entity, (With<BenchmarkEntity>, Without<ChildOf>)>,
 ) {
     for _event in events.read() {
         let count = query.iter().count();
         info!("Despawning {} benchmark entities", count);
-        for entity in query.iter() {
+        // Despawning a root takes its children with it, so only roots are
+        // despawned directly to avoid despawning children twice.
+        // `World::clear_entities` is faster (see the Bulk Despawn workload)
+        // but would also remove the camera and UI.
+        for entity in roots.iter() {
             commands.entity(entity).despawn();
         }
     }
@@ -238,11 +865,13 @@ pub fn start_benchmark(
     mut state: ResMut<BenchmarkState>,
     mut spawn_events: MessageWriter<SpawnEntitiesRequest>,
     mut metrics: ResMut<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
 ) {
     info!("Starting benchmark...");
 
     // Reset state
-    state.reset();
+    state.reset(&config, *workload);
     metrics.reset();
 
     // Spawn initial entities
@@ -273,10 +902,381 @@ pub fn reset_benchmark(
     mut state: ResMut<BenchmarkState>,
     mut metrics: ResMut<FrameMetrics>,
     mut despawn_events: MessageWriter<DespawnAllRequest>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
 ) {
     info!("Resetting benchmark...");
-    state.reset();
+    state.reset(&config, *workload);
     metrics.reset();
     despawn_events.write(DespawnAllRequest);
     next_phase.set(BenchmarkPhase::Idle);
 }
+
+// =============================================================================
+// Headless Runner
+// =============================================================================
+
+/// Headless benchmark driver for embedding the measurement loop elsewhere.
+///
+/// Builds a minimal `App` containing only the given spawn/process systems
+/// (no UI, no input, no binary search), spawns a fixed number of entities,
+/// skips the warm-up frames and then times each sampled `App::update`.
+///
+/// ```rust,no_run
+/// use bevy_ecs_benchmark::benchmark::runner::BenchmarkRunner;
+/// use bevy_ecs_benchmark::benchmark::workloads::{
+///     position_velocity_system, spawn_position_velocity_entities,
+/// };
+///
+/// let stats = BenchmarkRunner::new(spawn_position_velocity_entities, position_velocity_system)
+///     .entity_count(100_000)
+///     .run();
+/// println!("Median: {:.2}ms", stats.median);
+/// ```
+pub struct BenchmarkRunner {
+    app: App,
+    entity_count: usize,
+    warmup_frames: usize,
+    sample_frames: usize,
+    percentiles: Vec<f64>,
+    sub_windows: usize,
+}
+
+impl BenchmarkRunner {
+    /// Create a runner for a workload's spawn and process systems
+    pub fn new<M1, M2>(
+        spawn: impl IntoScheduleConfigs<ScheduleSystem, M1>,
+        process: impl IntoScheduleConfigs<ScheduleSystem, M2>,
+    ) -> Self {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<SpawnEntitiesRequest>()
+            // Resources the built-in workload systems depend on
+            .init_resource::<BenchmarkConfig>()
+            .init_resource::<FastRng>()
+            .init_resource::<FrameMetrics>()
+            .init_resource::<SpawnDespawnState>()
+            .init_resource::<ComponentToggleState>()
+            .init_resource::<ParIterProbe>()
+            .init_resource::<IterationSink>()
+            .configure_sets(Update, (BenchmarkSet::Spawn, BenchmarkSet::Process).chain())
+            .add_systems(Update, spawn.in_set(BenchmarkSet::Spawn))
+            .add_systems(Update, process.in_set(BenchmarkSet::Process));
+
+        Self {
+            app,
+            entity_count: INITIAL_ENTITY_COUNT,
+            warmup_frames: WARMUP_FRAMES,
+            sample_frames: SAMPLE_FRAMES,
+            percentiles: DEFAULT_PERCENTILES.to_vec(),
+            sub_windows: SAMPLE_SUB_WINDOWS,
+        }
+    }
+
+    /// Number of entities to spawn before warm-up
+    pub fn entity_count(mut self, count: usize) -> Self {
+        self.entity_count = count;
+        self
+    }
+
+    /// Frames to run before sampling (the first one also spawns entities)
+    pub fn warmup_frames(mut self, frames: usize) -> Self {
+        self.warmup_frames = frames;
+        self
+    }
+
+    /// Frames to time after warm-up
+    pub fn sample_frames(mut self, frames: usize) -> Self {
+        self.sample_frames = frames;
+        self
+    }
+
+    /// Frame time percentiles to compute (p95/p99 are always included)
+    pub fn percentiles(mut self, percentiles: Vec<f64>) -> Self {
+        self.percentiles = percentiles;
+        self
+    }
+
+    /// Sub-windows to split the samples into for `SampleStats::sub_window_medians`
+    pub fn sub_windows(mut self, sub_windows: usize) -> Self {
+        self.sub_windows = sub_windows;
+        self
+    }
+
+    /// Access the underlying `App` to insert extra resources or systems
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Drive the app to completion and return frame time statistics
+    pub fn run(self) -> SampleStats {
+        let mut stepped = self.start();
+        loop {
+            if let Some(stats) = stepped.step() {
+                return stats;
+            }
+        }
+    }
+
+    /// Request the entity spawn and hand back a runner that advances one
+    /// frame per `step`, for hosts that own the frame loop (e.g. a browser
+    /// `requestAnimationFrame` callback on wasm32, where blocking in `run`
+    /// would freeze the page)
+    pub fn start(mut self) -> SteppedBenchmark {
+        self.app.finish();
+        self.app.cleanup();
+
+        self.app.world_mut().write_message(SpawnEntitiesRequest {
+            count: self.entity_count,
+        });
+
+        SteppedBenchmark {
+            app: self.app,
+            warmup_remaining: self.warmup_frames,
+            sample_remaining: self.sample_frames,
+            metrics: FrameMetrics::default(),
+            percentiles: self.percentiles,
+            sub_windows: self.sub_windows,
+        }
+    }
+}
+
+/// A started `BenchmarkRunner` driven one frame at a time by the caller
+pub struct SteppedBenchmark {
+    app: App,
+    warmup_remaining: usize,
+    sample_remaining: usize,
+    metrics: FrameMetrics,
+    percentiles: Vec<f64>,
+    sub_windows: usize,
+}
+
+impl SteppedBenchmark {
+    /// Run one warm-up or sampled frame; returns the statistics once every
+    /// sample frame has been timed
+    pub fn step(&mut self) -> Option<SampleStats> {
+        if self.warmup_remaining > 0 {
+            self.warmup_remaining -= 1;
+            self.app.update();
+            return None;
+        }
+
+        if self.sample_remaining > 0 {
+            self.sample_remaining -= 1;
+            let start = Instant::now();
+            self.app.update();
+            self.metrics.add_sample(start.elapsed().as_secs_f64());
+        }
+
+        (self.sample_remaining == 0).then(|| {
+            self.metrics
+                .sample_stats(&self.percentiles, self.sub_windows)
+        })
+    }
+
+    /// Access the underlying `App` between steps
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::message::Messages;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+    use crate::benchmark::workloads::WorkloadsPlugin;
+    use crate::config::PAIRWISE_MAX_ENTITY_COUNT;
+
+    #[test]
+    fn grows_exponentially_until_the_target_is_exceeded() {
+        assert_eq!(
+            next_entity_count(1_000, 1_000, MAX_ENTITY_COUNT, false, true),
+            SearchDecision::Next { count: 2_000 }
+        );
+    }
+
+    #[test]
+    fn bisects_once_the_target_has_been_exceeded() {
+        // Over target at 2000 after staying under at 1000
+        assert_eq!(
+            next_entity_count(2_000, 1_000, 2_000, true, false),
+            SearchDecision::Next { count: 1_500 }
+        );
+        // Under target at 1500, so the next probe is above it
+        assert_eq!(
+            next_entity_count(1_500, 1_500, 2_000, false, false),
+            SearchDecision::Next { count: 1_750 }
+        );
+    }
+
+    #[test]
+    fn converges_on_the_last_count_under_target() {
+        assert_eq!(
+            next_entity_count(10_050, 10_000, 10_050, true, false),
+            SearchDecision::Converged { breakdown: 10_000 }
+        );
+        assert_eq!(
+            next_entity_count(10_000, 10_000, 10_050, false, false),
+            SearchDecision::Converged { breakdown: 10_000 }
+        );
+    }
+
+    #[test]
+    fn growth_is_capped_at_the_workload_limit() {
+        let cap = PAIRWISE_MAX_ENTITY_COUNT;
+        let grown = next_entity_count(cap / 2 + 1, cap / 2 + 1, MAX_ENTITY_COUNT, false, true);
+        assert_eq!(
+            cap_decision(grown, cap / 2 + 1, false, cap),
+            SearchDecision::Next { count: cap }
+        );
+    }
+
+    #[test]
+    fn staying_under_target_at_the_limit_reports_no_breakdown() {
+        let cap = PAIRWISE_MAX_ENTITY_COUNT;
+        let grown = next_entity_count(cap, cap, MAX_ENTITY_COUNT, false, true);
+        assert_eq!(
+            cap_decision(grown, cap, false, cap),
+            SearchDecision::LimitReached { count: cap }
+        );
+        // Exceeding the target at the limit still bisects below it
+        let bisected = next_entity_count(cap, cap / 2, cap, true, false);
+        assert_eq!(cap_decision(bisected, cap, true, cap), bisected);
+    }
+
+    fn assert_grows_within_limit(current: usize) {
+        let grown = grown_entity_count(current);
+        assert!(grown >= current, "{} shrank to {}", current, grown);
+        assert!(grown <= MAX_ENTITY_COUNT, "{} grew past the limit", current);
+    }
+
+    #[test]
+    fn growth_saturates_at_the_entity_limit() {
+        assert_grows_within_limit(MAX_ENTITY_COUNT - 1);
+        assert_grows_within_limit(MAX_ENTITY_COUNT);
+        assert_eq!(grown_entity_count(MAX_ENTITY_COUNT - 1), MAX_ENTITY_COUNT);
+        assert_eq!(grown_entity_count(MAX_ENTITY_COUNT), MAX_ENTITY_COUNT);
+    }
+
+    #[test]
+    fn growth_survives_f64_rounding() {
+        // Not representable as f64, so the product is computed from a rounded value
+        let just_past_f64_precision = (1 << f64::MANTISSA_DIGITS) + 1;
+        assert_ne!(
+            just_past_f64_precision as f64 as usize,
+            just_past_f64_precision
+        );
+        assert_grows_within_limit(just_past_f64_precision);
+
+        // Rounds up as f64, so the product lands exactly on the limit
+        let half_limit = MAX_ENTITY_COUNT / 2;
+        assert!(half_limit as f64 * GROWTH_MULTIPLIER >= MAX_ENTITY_COUNT as f64);
+        assert_grows_within_limit(half_limit);
+        assert_eq!(grown_entity_count(half_limit), MAX_ENTITY_COUNT);
+    }
+
+    #[test]
+    fn pairwise_search_starts_well_below_its_cap() {
+        let config = BenchmarkConfig::default();
+        let state = BenchmarkState::default();
+        let start = state.initial_count(&config, SelectedWorkload::PairwiseCombinations);
+        assert_eq!(start, crate::config::PAIRWISE_INITIAL_ENTITY_COUNT);
+        assert!(start < PAIRWISE_MAX_ENTITY_COUNT);
+        assert_eq!(
+            state.initial_count(&config, SelectedWorkload::SimpleIteration),
+            INITIAL_ENTITY_COUNT
+        );
+    }
+
+    fn runner_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, BenchmarkRunnerPlugin));
+        app.update();
+        app
+    }
+
+    /// `runner_app` with the workload systems and the resources they need
+    fn workload_app(workload: SelectedWorkload) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, BenchmarkRunnerPlugin))
+            .add_plugins(WorkloadsPlugin)
+            .init_resource::<FastRng>()
+            .init_resource::<SpawnDespawnState>()
+            .init_resource::<ComponentToggleState>()
+            .insert_resource(workload);
+        app.world_mut()
+            .resource_mut::<BenchmarkState>()
+            .start_entity_count = MIN_ENTITY_COUNT;
+        app.update();
+        app
+    }
+
+    #[test]
+    fn churn_workload_survives_two_adjusting_steps() {
+        let mut app = workload_app(SelectedWorkload::SpawnDespawn);
+        app.world_mut().run_system_once(start_benchmark).unwrap();
+
+        // Each Adjusting step despawns and requests a respawn; the churn
+        // system must not keep the world from emptying for it
+        let mut adjusted = 0;
+        let mut resampled = 0;
+        let mut last = BenchmarkPhase::Idle;
+        for _ in 0..2_000 {
+            app.update();
+            let phase = *app.world().resource::<State<BenchmarkPhase>>().get();
+            if phase != last {
+                match phase {
+                    BenchmarkPhase::Adjusting => adjusted += 1,
+                    BenchmarkPhase::Sampling if adjusted > 0 => resampled += 1,
+                    _ => {}
+                }
+                last = phase;
+            }
+            if resampled >= 2 {
+                break;
+            }
+        }
+
+        assert!(adjusted >= 2, "only {} Adjusting steps", adjusted);
+        assert!(resampled >= 2, "stuck after {} Adjusting steps", adjusted);
+        assert!(!app.world().resource::<PendingRespawn>().is_pending());
+    }
+
+    #[test]
+    fn empty_sample_set_aborts_the_suite() {
+        let mut app = runner_app();
+        app.world_mut()
+            .resource_mut::<NextState<AppState>>()
+            .set(AppState::Running);
+        app.update();
+        {
+            let mut state = app.world_mut().resource_mut::<BenchmarkState>();
+            state.automated = true;
+            state.suite_index = 1;
+        }
+        let entity_count = app.world().resource::<BenchmarkState>().entity_count;
+
+        // FrameMetrics has no samples, so sample_stats reports a count of 0
+        app.world_mut()
+            .run_system_once(adjust_entity_count)
+            .unwrap();
+        app.update();
+
+        let state = app.world().resource::<BenchmarkState>();
+        assert!(!state.automated);
+        assert_eq!(state.suite_index, 0);
+        // Not grown as if 0ms were under target
+        assert_eq!(state.entity_count, entity_count);
+        assert_eq!(
+            *app.world().resource::<State<AppState>>().get(),
+            AppState::Menu
+        );
+        assert!(app
+            .world()
+            .resource::<Messages<BenchmarkComplete>>()
+            .is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! Resource contention workloads - testing scheduler parallelism.
+//!
+//! These workloads run no queries. Instead, several systems do the same
+//! amount of CPU work and differ only in which resource they write to.
+//!
+//! # Two Approaches Demonstrated
+//!
+//! 1. **Shared Resource** (Contention):
+//!    - Every system takes `ResMut<SharedAccumulator>`
+//!    - The scheduler must run them one after another
+//!    - Total frame cost is the SUM of all system costs
+//!
+//! 2. **Distinct Resources** (Parallel):
+//!    - Each system takes its own `ResMut<Accumulator<N>>`
+//!    - No conflicts, so the multi-threaded executor runs them concurrently
+//!    - Total frame cost approaches the cost of the SLOWEST system
+//!
+//! The entity count sets the amount of work: it is split evenly across
+//! `CONTENTION_SYSTEMS` systems. Bare `BenchmarkEntity` markers are still
+//! spawned so the dashboard count matches the requested size.
+//!
+//! Resource Churn is the odd one out: it inserts and removes a resource
+//! through `Commands` a fixed number of times per frame, so its cost does
+//! not depend on the entity count at all.
+
+use bevy::prelude::*;
+use std::hint::black_box;
+
+use crate::benchmark::runner::SpawnEntitiesRequest;
+use crate::components::BenchmarkEntity;
+
+/// Number of systems competing for resources
+pub const CONTENTION_SYSTEMS: usize = 8;
+
+/// Work size shared by all contention systems
+#[derive(Resource, Default)]
+pub struct ContentionWork {
+    /// Iterations each system performs per frame
+    pub iterations_per_system: usize,
+}
+
+/// Single resource written by every system in the contention workload
+#[derive(Resource, Default)]
+pub struct SharedAccumulator(pub u64);
+
+/// Per-system resource for the parallel workload (one type per `N`)
+#[derive(Resource, Default)]
+pub struct Accumulator<const N: usize>(pub u64);
+
+/// Initialize all resources used by the contention workloads
+pub fn init_contention_resources(app: &mut App) {
+    app.init_resource::<ContentionWork>()
+        .init_resource::<SharedAccumulator>()
+        .init_resource::<Accumulator<0>>()
+        .init_resource::<Accumulator<1>>()
+        .init_resource::<Accumulator<2>>()
+        .init_resource::<Accumulator<3>>()
+        .init_resource::<Accumulator<4>>()
+        .init_resource::<Accumulator<5>>()
+        .init_resource::<Accumulator<6>>()
+        .init_resource::<Accumulator<7>>();
+}
+
+/// Set the per-system work size and spawn marker entities.
+pub fn spawn_contention_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut work: ResMut<ContentionWork>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!(
+            "Setting up resource contention: {} iterations across {} systems",
+            event.count, CONTENTION_SYSTEMS
+        );
+
+        work.iterations_per_system = event.count / CONTENTION_SYSTEMS;
+
+        let entities: Vec<_> = (0..event.count).map(|_| BenchmarkEntity).collect();
+        commands.spawn_batch(entities);
+    }
+}
+
+/// CPU-bound busy work, identical for every system
+#[inline]
+fn busy_work(iterations: usize, seed: u64) -> u64 {
+    let mut acc = seed;
+    for i in 0..iterations {
+        acc = acc
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(black_box(i as u64));
+    }
+    acc
+}
+
+/// Contention system: all instances write the same resource, so they serialize.
+///
+/// The resource is only touched once per run - the conflict is purely a
+/// scheduling constraint, which is exactly what this workload isolates.
+pub fn shared_resource_system<const N: usize>(
+    mut shared: ResMut<SharedAccumulator>,
+    work: Res<ContentionWork>,
+) {
+    let result = busy_work(work.iterations_per_system, N as u64);
+    shared.0 = shared.0.wrapping_add(result);
+}
+
+/// Parallel system: each instance writes its own resource, so they can overlap.
+pub fn distinct_resource_system<const N: usize>(
+    mut own: ResMut<Accumulator<N>>,
+    work: Res<ContentionWork>,
+) {
+    let result = busy_work(work.iterations_per_system, N as u64);
+    own.0 = own.0.wrapping_add(result);
+}
+
+/// Insert/remove cycles Resource Churn queues per frame
+pub const RESOURCE_CHURN_CYCLES: usize = 1_000;
+
+/// Marker resource repeatedly inserted and removed by Resource Churn
+#[derive(Resource, Default)]
+pub struct ResourceChurnMarker(pub u64);
+
+/// Queue `RESOURCE_CHURN_CYCLES` insert/remove pairs for the marker.
+///
+/// Each pair is applied at the next sync point, so the resource exists
+/// only between its own insert and remove.
+pub fn resource_churn_system(mut commands: Commands) {
+    for cycle in 0..RESOURCE_CHURN_CYCLES {
+        commands.insert_resource(ResourceChurnMarker(cycle as u64));
+        commands.remove_resource::<ResourceChurnMarker>();
+    }
+}
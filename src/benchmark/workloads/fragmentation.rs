@@ -19,7 +19,13 @@
 //!
 //! Use marker components when query-level filtering is essential.
 //! Use EntityVariant when iteration performance is critical.
+//!
+//! The Defrag Recovery workload checks the way back: entities start spread
+//! across up to 256 archetypes, then lose their markers and collapse into
+//! one. The emptied archetypes stay registered, so the question is whether
+//! iteration returns to single-archetype speed.
 
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
 use rand::Rng;
 use std::hint::black_box;
@@ -29,6 +35,7 @@ use crate::components::{
     BenchmarkEntity, EntityVariant, FastRng, Position, Velocity,
     VariantA, VariantB, VariantC, VariantD, VariantE, VariantF, VariantG, VariantH,
 };
+use crate::config::BenchmarkConfig;
 
 /// Number of different archetype variants to create
 pub const ARCHETYPE_VARIANTS: usize = 8;
@@ -46,19 +53,29 @@ pub fn spawn_fragmented_entities(
     mut commands: Commands,
     mut spawn_events: MessageReader<SpawnEntitiesRequest>,
     mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
 ) {
     if let Some(event) = spawn_events.read().last() {
+        // Entities per archetype pattern, per `BenchmarkConfig::fragment_distribution`
+        let counts = config
+            .fragment_distribution
+            .split(event.count, ARCHETYPE_VARIANTS);
+
         info!(
-            "Spawning {} entities across {} archetypes (FRAGMENTED)",
-            event.count, ARCHETYPE_VARIANTS
+            "Spawning {} entities across {} archetypes (FRAGMENTED): {:?}",
+            event.count, ARCHETYPE_VARIANTS, counts
         );
 
         // NOTE: We're deliberately NOT using batch spawning here because
         // entities go into different archetypes. For fragmented spawns,
         // individual spawns are necessary.
 
-        for i in 0..event.count {
-            let variant = i % ARCHETYPE_VARIANTS;
+        let variants = counts
+            .iter()
+            .enumerate()
+            .flat_map(|(variant, &n)| std::iter::repeat_n(variant, n));
+
+        for variant in variants {
             let pos = Position::random_with(&mut rng.0);
             let vel = Velocity::random_with(&mut rng.0);
 
@@ -177,24 +194,48 @@ pub fn spawn_heavily_fragmented_entities(
     mut rng: ResMut<FastRng>,
 ) {
     if let Some(event) = spawn_events.read().last() {
-        info!("Spawning {} entities across up to 256 archetypes (EXTREME)", event.count);
+        info!(
+            "Spawning {} entities across up to 256 archetypes (EXTREME)",
+            event.count
+        );
+        spawn_random_variants(&mut commands, &mut rng, event.count);
+    }
+}
 
-        for _ in 0..event.count {
-            let pos = Position::random_with(&mut rng.0);
-            let vel = Velocity::random_with(&mut rng.0);
+/// Spawn `count` entities, each with a random subset of the eight variant
+/// markers (up to 256 archetypes)
+fn spawn_random_variants(commands: &mut Commands, rng: &mut FastRng, count: usize) {
+    for _ in 0..count {
+        let pos = Position::random_with(&mut rng.0);
+        let vel = Velocity::random_with(&mut rng.0);
 
-            // Randomly assign variants creating up to 256 archetypes
-            let mut entity_commands = commands.spawn((BenchmarkEntity, pos, vel));
-
-            // Each if statement potentially adds to a different archetype
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantA); }
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantB); }
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantC); }
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantD); }
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantE); }
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantF); }
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantG); }
-            if rng.0.gen_bool(0.5) { entity_commands.insert(VariantH); }
+        // Randomly assign variants creating up to 256 archetypes
+        let mut entity_commands = commands.spawn((BenchmarkEntity, pos, vel));
+
+        // Each if statement potentially adds to a different archetype
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantA);
+        }
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantB);
+        }
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantC);
+        }
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantD);
+        }
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantE);
+        }
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantF);
+        }
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantG);
+        }
+        if rng.0.gen_bool(0.5) {
+            entity_commands.insert(VariantH);
         }
     }
 }
@@ -290,3 +331,240 @@ pub fn unified_filtered_query_system(
 
     black_box(sum);
 }
+
+// =============================================================================
+// Or Filter Workload
+// =============================================================================
+
+/// Spawn entities tagged with VariantA, VariantB, or neither.
+///
+/// Produces three archetypes: two that match the `Or` filter and one that
+/// doesn't, so the query has to evaluate both branches during matching.
+pub fn spawn_or_filter_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} entities for Or filter query", event.count);
+
+        let third = event.count / 3;
+
+        // Each group is a single archetype, so batch spawning still applies
+        let with_a: Vec<_> = (0..third)
+            .map(|_| (BenchmarkEntity, Position::random_with(&mut rng.0), VariantA))
+            .collect();
+
+        let with_b: Vec<_> = (0..third)
+            .map(|_| (BenchmarkEntity, Position::random_with(&mut rng.0), VariantB))
+            .collect();
+
+        let untagged: Vec<_> = (third * 2..event.count)
+            .map(|_| (BenchmarkEntity, Position::random_with(&mut rng.0)))
+            .collect();
+
+        commands.spawn_batch(with_a);
+        commands.spawn_batch(with_b);
+        commands.spawn_batch(untagged);
+    }
+}
+
+/// Iterate entities matching `Or<(With<VariantA>, With<VariantB>)>`.
+///
+/// Compare against `unified_filtered_query_system`, which performs the same
+/// A-or-B selection as a runtime bitflag check over a single archetype.
+/// The `Or` filter skips non-matching archetypes entirely, so it wins when
+/// few entities match and loses ground as archetype count grows.
+pub fn or_filter_query_system(
+    query: Query<&Position, (With<BenchmarkEntity>, Or<(With<VariantA>, With<VariantB>)>)>,
+) {
+    let mut sum: f32 = 0.0;
+
+    for pos in &query {
+        sum += black_box(pos.x + pos.y + pos.z);
+    }
+
+    black_box(sum);
+}
+
+// =============================================================================
+// Bitflag Branching Workload
+// =============================================================================
+
+/// Run condition: Bitflag Branching is measuring the `has()` checks
+pub fn bitflag_checks_enabled(config: Res<BenchmarkConfig>) -> bool {
+    config.bitflag_checks
+}
+
+/// Same fetch as `bitflag_branch_system`, with no flag checks.
+///
+/// Reads every component, including `EntityVariant`, so the only
+/// difference between the two sub-variants is the branching.
+pub fn bitflag_no_check_system(
+    query: Query<(&Position, &Velocity, &EntityVariant), With<BenchmarkEntity>>,
+) {
+    let mut sum: f32 = 0.0;
+    for (pos, vel, variant) in &query {
+        black_box(variant);
+        sum += black_box(pos.x * vel.x + pos.y * vel.y + pos.z * vel.z);
+    }
+    black_box(sum);
+}
+
+/// Branch on random `EntityVariant` flags per entity.
+///
+/// Flags are random, so the branches are unpredictable - this measures the
+/// runtime-check "CON" of the unified approach that
+/// `unified_variant_iteration_system` mixes in with iteration cost.
+pub fn bitflag_branch_system(
+    query: Query<(&Position, &Velocity, &EntityVariant), With<BenchmarkEntity>>,
+) {
+    let mut sum: f32 = 0.0;
+    for (pos, vel, variant) in &query {
+        if variant.has(EntityVariant::A) {
+            sum += black_box(pos.x * vel.x);
+        }
+        if variant.has(EntityVariant::B) {
+            sum += black_box(pos.y * vel.y);
+        }
+        if variant.has(EntityVariant::C) {
+            sum += black_box(pos.z * vel.z);
+        }
+    }
+    black_box(sum);
+}
+
+// =============================================================================
+// Defrag Recovery Workload
+// =============================================================================
+
+/// Frames iterated fragmented before the variant markers are stripped
+pub const DEFRAG_AFTER_FRAMES: usize = 10;
+
+/// Untimed passes before each layout is measured, so neither side's
+/// timings include the cold caches left by the spawn or the marker removal
+pub const DEFRAG_WARMUP_PASSES: usize = 5;
+
+/// Iteration timings either side of the defragmentation, for the current count
+#[derive(Resource, Default)]
+pub struct DefragRecoveryState {
+    /// Iteration times (ms) while entities were spread across archetypes
+    pub fragmented_ms: Vec<f64>,
+    /// Iteration times (ms) once every entity is back in one archetype
+    pub recovered_ms: Vec<f64>,
+    /// Whether the marker removal has been queued
+    pub defragmented: bool,
+    /// Untimed passes left before the current layout is measured
+    pub warmup_remaining: usize,
+}
+
+impl DefragRecoveryState {
+    /// Median fragmented iteration time in milliseconds
+    pub fn fragmented_median_ms(&self) -> Option<f64> {
+        median_ms(&self.fragmented_ms)
+    }
+
+    /// Median recovered iteration time in milliseconds
+    pub fn recovered_median_ms(&self) -> Option<f64> {
+        median_ms(&self.recovered_ms)
+    }
+}
+
+fn median_ms(times: &[f64]) -> Option<f64> {
+    if times.is_empty() {
+        return None;
+    }
+    let mut sorted = times.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    Some(if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    })
+}
+
+/// Spawn heavily fragmented entities and start a fresh fragmented/recovered
+/// measurement. Logs both medians at the previous count.
+pub fn spawn_defrag_recovery_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+    mut state: ResMut<DefragRecoveryState>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        if let (Some(fragmented), Some(recovered)) =
+            (state.fragmented_median_ms(), state.recovered_median_ms())
+        {
+            info!(
+                "Defrag recovery: iteration {:.3}ms fragmented, {:.3}ms recovered",
+                fragmented, recovered
+            );
+        }
+        info!(
+            "Spawning {} entities across up to 256 archetypes to defragment",
+            event.count
+        );
+
+        *state = DefragRecoveryState {
+            warmup_remaining: DEFRAG_WARMUP_PASSES,
+            ..default()
+        };
+        spawn_random_variants(&mut commands, &mut rng, event.count);
+    }
+}
+
+/// Time one pass over every entity, filed under the current archetype layout
+/// once that layout's warm-up passes are done
+pub fn defrag_iteration_system(
+    query: Query<(&Position, &Velocity), With<BenchmarkEntity>>,
+    mut state: ResMut<DefragRecoveryState>,
+) {
+    if query.is_empty() {
+        return;
+    }
+
+    let start = Instant::now();
+    let mut sum: f32 = 0.0;
+    for (pos, vel) in &query {
+        sum += black_box(pos.x * vel.x + pos.y * vel.y + pos.z * vel.z);
+    }
+    black_box(sum);
+    let ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    if state.warmup_remaining > 0 {
+        state.warmup_remaining -= 1;
+    } else if state.defragmented {
+        state.recovered_ms.push(ms);
+    } else {
+        state.fragmented_ms.push(ms);
+    }
+}
+
+/// Strip every variant marker after `DEFRAG_AFTER_FRAMES` timed fragmented passes,
+/// moving all entities into the single `(BenchmarkEntity, Position, Velocity)`
+/// archetype. Runs once per spawn.
+pub fn defragment_system(
+    mut commands: Commands,
+    query: Query<Entity, With<BenchmarkEntity>>,
+    mut state: ResMut<DefragRecoveryState>,
+) {
+    if state.defragmented || state.fragmented_ms.len() < DEFRAG_AFTER_FRAMES {
+        return;
+    }
+
+    for entity in &query {
+        commands.entity(entity).remove::<(
+            VariantA,
+            VariantB,
+            VariantC,
+            VariantD,
+            VariantE,
+            VariantF,
+            VariantG,
+            VariantH,
+        )>();
+    }
+    state.defragmented = true;
+    state.warmup_remaining = DEFRAG_WARMUP_PASSES;
+}
@@ -7,13 +7,41 @@
 //! - **FastRng for bulk spawning**: Pre-generate random data efficiently
 //! - **Pre-allocation**: Collect entities before spawn_batch
 
+use bevy::ecs::entity::EntityHashMap;
 use bevy::prelude::*;
+use rand::seq::SliceRandom;
 use std::hint::black_box;
 
 use crate::benchmark::runner::SpawnEntitiesRequest;
 use crate::components::{
-    Acceleration, BenchmarkEntity, Counter, DataPayload, FastRng, Position, Velocity,
+    Acceleration, BenchmarkEntity, Counter, DataPayload, FastRng, Health, Position, PositionSparse,
+    Stats, VariablePayload, Velocity,
 };
+use crate::config::{BenchmarkConfig, MAX_MULTI_COMPONENT_COUNT};
+
+/// Where the core iteration workloads store their sums, so the loops stay
+/// live when `BenchmarkConfig::black_box_reads` is off
+#[derive(Resource, Default)]
+pub struct IterationSink(pub f64);
+
+/// Run condition: the core iteration workloads `black_box` each read
+pub fn black_box_reads_enabled(config: Res<BenchmarkConfig>) -> bool {
+    config.black_box_reads
+}
+
+/// `black_box` the value only when `OPAQUE` is set.
+///
+/// The systems using it take `OPAQUE` as a const parameter and are
+/// registered once per setting, so the plain path has no barrier or branch
+/// in its loop at all.
+#[inline(always)]
+fn barrier<const OPAQUE: bool, T>(value: T) -> T {
+    if OPAQUE {
+        black_box(value)
+    } else {
+        value
+    }
+}
 
 // =============================================================================
 // Simple Iteration Workload
@@ -41,14 +69,17 @@ pub fn spawn_simple_iteration_entities(
 /// Simple read-only iteration over entities
 ///
 /// This tests the raw overhead of iterating entities with a single component.
-pub fn simple_iteration_system(query: Query<&Counter, With<BenchmarkEntity>>) {
+pub fn simple_iteration_system<const OPAQUE: bool>(
+    query: Query<&Counter, With<BenchmarkEntity>>,
+    mut sink: ResMut<IterationSink>,
+) {
     let mut sum: u64 = 0;
     for counter in &query {
         // Use black_box to prevent the compiler from optimizing away the read
-        sum = sum.wrapping_add(black_box(counter.value));
+        sum = sum.wrapping_add(barrier::<OPAQUE, _>(counter.value));
     }
     // Prevent dead code elimination
-    black_box(sum);
+    sink.0 = black_box(sum) as f64;
 }
 
 // =============================================================================
@@ -82,20 +113,114 @@ pub fn spawn_multi_component_entities(
     }
 }
 
+/// Spawn the Multi-Component Read data: all `MAX_MULTI_COMPONENT_COUNT`
+/// components on every entity, so changing the read count only changes
+/// the query width, never the archetype.
+pub fn spawn_wide_component_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!(
+            "Spawning {} entities with {} components for multi-component read",
+            event.count, MAX_MULTI_COMPONENT_COUNT
+        );
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|i| {
+                (
+                    BenchmarkEntity,
+                    Position::random_with(&mut rng.0),
+                    Velocity::random_with(&mut rng.0),
+                    Acceleration::random_with(&mut rng.0),
+                    Health::default(),
+                    Stats::random_with(&mut rng.0),
+                    Counter { value: i as u64 },
+                )
+            })
+            .collect();
+
+        commands.spawn_batch(entities);
+    }
+}
+
 /// Read multiple components per entity
 ///
 /// This tests cache efficiency when reading larger amounts of data per entity.
-pub fn multi_component_read_system(
-    query: Query<(&Position, &Velocity, &Acceleration), With<BenchmarkEntity>>,
+/// `BenchmarkConfig::multi_component_count` picks the query width, from
+/// `Position` alone up to all six components.
+pub fn multi_component_read_system<const OPAQUE: bool>(
+    one: Query<&Position, With<BenchmarkEntity>>,
+    two: Query<(&Position, &Velocity), With<BenchmarkEntity>>,
+    three: Query<(&Position, &Velocity, &Acceleration), With<BenchmarkEntity>>,
+    four: Query<(&Position, &Velocity, &Acceleration, &Health), With<BenchmarkEntity>>,
+    five: Query<(&Position, &Velocity, &Acceleration, &Health, &Stats), With<BenchmarkEntity>>,
+    six: Query<
+        (
+            &Position,
+            &Velocity,
+            &Acceleration,
+            &Health,
+            &Stats,
+            &Counter,
+        ),
+        With<BenchmarkEntity>,
+    >,
+    config: Res<BenchmarkConfig>,
+    mut sink: ResMut<IterationSink>,
 ) {
     let mut sum: f32 = 0.0;
-    for (pos, vel, acc) in &query {
-        // Compute something using all three components
-        sum += black_box(pos.x + vel.x + acc.x);
-        sum += black_box(pos.y + vel.y + acc.y);
-        sum += black_box(pos.z + vel.z + acc.z);
+    match config
+        .multi_component_count
+        .clamp(1, MAX_MULTI_COMPONENT_COUNT)
+    {
+        1 => {
+            for pos in &one {
+                sum += barrier::<OPAQUE, _>(pos.x + pos.y + pos.z);
+            }
+        }
+        2 => {
+            for (pos, vel) in &two {
+                sum += barrier::<OPAQUE, _>(pos.x + vel.x);
+                sum += barrier::<OPAQUE, _>(pos.y + vel.y);
+                sum += barrier::<OPAQUE, _>(pos.z + vel.z);
+            }
+        }
+        3 => {
+            for (pos, vel, acc) in &three {
+                // Compute something using all three components
+                sum += barrier::<OPAQUE, _>(pos.x + vel.x + acc.x);
+                sum += barrier::<OPAQUE, _>(pos.y + vel.y + acc.y);
+                sum += barrier::<OPAQUE, _>(pos.z + vel.z + acc.z);
+            }
+        }
+        4 => {
+            for (pos, vel, acc, health) in &four {
+                sum += barrier::<OPAQUE, _>(pos.x + vel.x + acc.x);
+                sum += barrier::<OPAQUE, _>(pos.y + vel.y + acc.y);
+                sum += barrier::<OPAQUE, _>(pos.z + vel.z + acc.z);
+                sum += barrier::<OPAQUE, _>(health.current);
+            }
+        }
+        5 => {
+            for (pos, vel, acc, health, stats) in &five {
+                sum += barrier::<OPAQUE, _>(pos.x + vel.x + acc.x + stats.strength);
+                sum += barrier::<OPAQUE, _>(pos.y + vel.y + acc.y + stats.speed);
+                sum += barrier::<OPAQUE, _>(pos.z + vel.z + acc.z + stats.defense);
+                sum += barrier::<OPAQUE, _>(health.current);
+            }
+        }
+        _ => {
+            for (pos, vel, acc, health, stats, counter) in &six {
+                sum += barrier::<OPAQUE, _>(pos.x + vel.x + acc.x + stats.strength);
+                sum += barrier::<OPAQUE, _>(pos.y + vel.y + acc.y + stats.speed);
+                sum += barrier::<OPAQUE, _>(pos.z + vel.z + acc.z + stats.defense);
+                sum += barrier::<OPAQUE, _>(health.current + counter.value as f32);
+            }
+        }
     }
-    black_box(sum);
+    sink.0 = black_box(sum) as f64;
 }
 
 // =============================================================================
@@ -129,3 +254,366 @@ pub fn heavy_data_read_system(query: Query<&DataPayload, With<BenchmarkEntity>>)
     }
     black_box(sum);
 }
+
+// =============================================================================
+// Variable Payload Workload
+// =============================================================================
+
+/// Spawn entities whose payload length comes from `BenchmarkConfig`.
+///
+/// Sweep `variable_payload_len` across runs to find where cache effects
+/// start to dominate iteration cost.
+pub fn spawn_variable_payload_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        let len = config.variable_payload_len;
+        info!(
+            "Spawning {} entities with {}-float variable payloads",
+            event.count, len
+        );
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|_| {
+                (
+                    BenchmarkEntity,
+                    VariablePayload::random_with(&mut rng.0, len),
+                )
+            })
+            .collect();
+
+        commands.spawn_batch(entities);
+    }
+}
+
+/// Sum every value of a runtime-sized payload
+pub fn variable_payload_read_system(query: Query<&VariablePayload, With<BenchmarkEntity>>) {
+    let mut sum: f32 = 0.0;
+    for payload in &query {
+        for &value in payload.values.iter() {
+            sum += black_box(value);
+        }
+    }
+    black_box(sum);
+}
+
+// =============================================================================
+// Entity Id Iteration Workloads
+// =============================================================================
+
+/// Spawn entities with only a Position, shared by the entity id comparison pair.
+pub fn spawn_position_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} entities with Position", event.count);
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|_| (BenchmarkEntity, Position::random_with(&mut rng.0)))
+            .collect();
+
+        commands.spawn_batch(entities);
+    }
+}
+
+/// Component-only iteration - baseline for `entity_id_iteration_system`
+pub fn position_iteration_system<const OPAQUE: bool>(
+    query: Query<&Position, With<BenchmarkEntity>>,
+    mut sink: ResMut<IterationSink>,
+) {
+    let mut sum: f32 = 0.0;
+    for pos in &query {
+        sum += barrier::<OPAQUE, _>(pos.x + pos.y + pos.z);
+    }
+    sink.0 = black_box(sum) as f64;
+}
+
+/// Run condition: Position Iteration is measuring sparse-set storage
+pub fn sparse_storage_enabled(config: Res<BenchmarkConfig>) -> bool {
+    config.sparse_storage
+}
+
+/// Spawn the Position Iteration data as `PositionSparse`.
+///
+/// Draws the same random values as `spawn_position_entities`, so the only
+/// difference between the two runs is the storage type.
+pub fn spawn_position_sparse_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} entities with PositionSparse", event.count);
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|_| {
+                let position = Position::random_with(&mut rng.0);
+                (BenchmarkEntity, PositionSparse::from(position))
+            })
+            .collect();
+
+        commands.spawn_batch(entities);
+    }
+}
+
+/// Sparse-set counterpart of `position_iteration_system`.
+///
+/// Sparse-set components live in their own dense array instead of the
+/// archetype table, so iteration goes through an extra indirection per entity.
+pub fn position_sparse_iteration_system<const OPAQUE: bool>(
+    query: Query<&PositionSparse, With<BenchmarkEntity>>,
+    mut sink: ResMut<IterationSink>,
+) {
+    let mut sum: f32 = 0.0;
+    for pos in &query {
+        sum += barrier::<OPAQUE, _>(pos.x + pos.y + pos.z);
+    }
+    sink.0 = black_box(sum) as f64;
+}
+
+/// Iterate `(Entity, &Position)` and read the entity id as well.
+///
+/// Fetching `Entity` reads from the table's entity column in addition to
+/// the component column, so each entity touches one more stream of memory.
+/// Compare against `position_iteration_system` on identical data.
+pub fn entity_id_iteration_system<const OPAQUE: bool>(
+    query: Query<(Entity, &Position), With<BenchmarkEntity>>,
+    mut sink: ResMut<IterationSink>,
+) {
+    let mut sum: f32 = 0.0;
+    let mut ids: u64 = 0;
+    for (entity, pos) in &query {
+        ids = ids.wrapping_add(barrier::<OPAQUE, _>(entity.to_bits()));
+        sum += barrier::<OPAQUE, _>(pos.x + pos.y + pos.z);
+    }
+    let (sum, ids) = black_box((sum, ids));
+    sink.0 = sum as f64 + ids as f64;
+}
+
+// =============================================================================
+// Random Access Workload
+// =============================================================================
+
+/// Entity ids of the random access workload, in shuffled lookup order
+#[derive(Resource, Default)]
+pub struct RandomAccessOrder {
+    pub entities: Vec<Entity>,
+}
+
+/// Spawn Position entities and record their ids in a shuffled order.
+///
+/// Uses the same data layout as `spawn_position_entities`, so the
+/// Position Iteration workload is the linear baseline for this one.
+pub fn spawn_random_access_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+    mut order: ResMut<RandomAccessOrder>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} entities for random access", event.count);
+
+        let mut entities = Vec::with_capacity(event.count);
+        for _ in 0..event.count {
+            let position = Position::random_with(&mut rng.0);
+            entities.push(commands.spawn((BenchmarkEntity, position)).id());
+        }
+
+        entities.shuffle(&mut rng.0);
+        order.entities = entities;
+    }
+}
+
+/// Look up every entity with `query.get` in shuffled order.
+///
+/// Each lookup goes through the entity location table and then jumps to
+/// an unpredictable table row, defeating the prefetcher. Linear iteration
+/// over the same data (Position Iteration) walks memory sequentially,
+/// which is why random lookups in hot loops are so much slower.
+pub fn random_access_system(
+    query: Query<&Position, With<BenchmarkEntity>>,
+    order: Res<RandomAccessOrder>,
+) {
+    let mut sum: f32 = 0.0;
+    for &entity in &order.entities {
+        if let Ok(pos) = query.get(black_box(entity)) {
+            sum += black_box(pos.x + pos.y + pos.z);
+        }
+    }
+    black_box(sum);
+}
+
+// =============================================================================
+// Query Transmute / Join Workloads
+// =============================================================================
+
+/// Two passes with plain queries - baseline for `query_transmute_system`.
+///
+/// Pass 1 reads Position only, pass 2 reads Position + Velocity, matching
+/// the work done through the lenses.
+pub fn combined_query_read_system(
+    positions: Query<&Position, With<BenchmarkEntity>>,
+    combined: Query<(&Position, &Velocity), With<BenchmarkEntity>>,
+) {
+    let mut sum: f32 = 0.0;
+    for pos in &positions {
+        sum += black_box(pos.x);
+    }
+    for (pos, vel) in &combined {
+        sum += black_box(pos.x * vel.x);
+    }
+    black_box(sum);
+}
+
+/// The same two passes through `QueryLens`es.
+///
+/// Pass 1 transmutes `(&Position, &Velocity)` down to `&Position`, pass 2
+/// joins separate Position and Velocity queries into one. Each lens builds
+/// a fresh `QueryState` every frame (matching archetypes and, for joins,
+/// intersecting the two queries), and joined iteration goes through
+/// per-entity lookups rather than dense table walks.
+pub fn query_transmute_system(
+    mut combined: Query<(&Position, &Velocity), With<BenchmarkEntity>>,
+    mut positions: Query<&Position, With<BenchmarkEntity>>,
+    mut velocities: Query<&Velocity>,
+) {
+    let mut sum: f32 = 0.0;
+
+    let mut position_lens = combined.transmute_lens::<&Position>();
+    for pos in &position_lens.query() {
+        sum += black_box(pos.x);
+    }
+
+    let mut joined = positions.join::<_, (&Position, &Velocity)>(&mut velocities);
+    for (pos, vel) in &joined.query() {
+        sum += black_box(pos.x * vel.x);
+    }
+
+    black_box(sum);
+}
+
+// =============================================================================
+// Pairwise Combinations Workload
+// =============================================================================
+
+/// Sum the distance between every pair of entities with `iter_combinations`.
+///
+/// Quadratic: n entities give n(n-1)/2 pairs per frame, so the breakdown
+/// point is orders of magnitude below the linear workloads and the search
+/// is capped at `PAIRWISE_MAX_ENTITY_COUNT`. Compare it against other
+/// pairwise workloads, not against the linear ones.
+pub fn pairwise_distance_system(query: Query<&Position, With<BenchmarkEntity>>) {
+    let mut sum: f32 = 0.0;
+    for [a, b] in query.iter_combinations() {
+        let (dx, dy, dz) = (a.x - b.x, a.y - b.y, a.z - b.z);
+        sum += black_box((dx * dx + dy * dy + dz * dz).sqrt());
+    }
+    black_box(sum);
+}
+
+// =============================================================================
+// Iteration Style Workload
+// =============================================================================
+
+/// Run condition: Iter Style is measuring `iter().for_each`
+pub fn iter_for_each_enabled(config: Res<BenchmarkConfig>) -> bool {
+    config.iter_for_each
+}
+
+/// Sum Position and Velocity with `query.iter().for_each(...)`.
+///
+/// `for_each` drives the iterator through internal iteration, which can let
+/// the compiler hoist the per-table bookkeeping out of the inner loop.
+/// Compare against `for_loop_style_system` on the same data.
+pub fn for_each_style_system(query: Query<(&Position, &Velocity), With<BenchmarkEntity>>) {
+    let mut sum: f32 = 0.0;
+    query.iter().for_each(|(pos, vel)| {
+        sum += black_box(pos.x * vel.x + pos.y * vel.y + pos.z * vel.z);
+    });
+    black_box(sum);
+}
+
+/// Sum Position and Velocity with `for x in &query`.
+///
+/// External iteration calls `next()` per item; the body is otherwise
+/// identical to `for_each_style_system`.
+pub fn for_loop_style_system(query: Query<(&Position, &Velocity), With<BenchmarkEntity>>) {
+    let mut sum: f32 = 0.0;
+    for (pos, vel) in &query {
+        sum += black_box(pos.x * vel.x + pos.y * vel.y + pos.z * vel.z);
+    }
+    black_box(sum);
+}
+
+// =============================================================================
+// Entity Map Lookup Workload
+// =============================================================================
+
+/// `HashMap<Entity, T>`-style side table, one entry per benchmark entity
+#[derive(Resource, Default)]
+pub struct EntityLookupTable(pub EntityHashMap<usize>);
+
+/// Run condition: Entity Map Lookup reads through the side table
+pub fn entity_map_enabled(config: Res<BenchmarkConfig>) -> bool {
+    !config.entity_map_direct
+}
+
+/// Spawn entities with a `Counter` and rebuild the side table to match.
+///
+/// Entities are spawned one by one so each id is known up front; the table
+/// maps every entity to the same value its `Counter` holds.
+pub fn spawn_entity_map_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut table: ResMut<EntityLookupTable>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!(
+            "Spawning {} entities with an EntityHashMap side table",
+            event.count
+        );
+
+        table.0.clear();
+        table.0.reserve(event.count);
+        for index in 0..event.count {
+            let entity = commands
+                .spawn((
+                    BenchmarkEntity,
+                    Counter {
+                        value: index as u64,
+                    },
+                ))
+                .id();
+            table.0.insert(entity, index);
+        }
+    }
+}
+
+/// One `EntityHashMap::get` per entity: the side-table path
+pub fn entity_map_lookup_system(
+    query: Query<Entity, With<BenchmarkEntity>>,
+    table: Res<EntityLookupTable>,
+) {
+    let mut sum: usize = 0;
+    for entity in &query {
+        if let Some(&value) = table.0.get(&entity) {
+            sum = sum.wrapping_add(black_box(value));
+        }
+    }
+    black_box(sum);
+}
+
+/// Same loop reading `Counter` from the query: the direct component path
+pub fn entity_map_direct_system(query: Query<(Entity, &Counter), With<BenchmarkEntity>>) {
+    let mut sum: usize = 0;
+    for (entity, counter) in &query {
+        black_box(entity);
+        sum = sum.wrapping_add(black_box(counter.value as usize));
+    }
+    black_box(sum);
+}
@@ -16,21 +16,32 @@
 //! 1. Spawn systems run before workload systems
 //! 2. Multiple independent workload systems could theoretically run in parallel
 
+mod contention;
 mod fragmentation;
 mod iteration;
 mod mutation;
+mod render;
+mod scheduling;
 mod structural;
 
+pub use contention::*;
 pub use fragmentation::*;
 pub use iteration::*;
 pub use mutation::*;
+pub use render::*;
+pub use scheduling::*;
 pub use structural::*;
 
 use bevy::prelude::*;
-use bevy::ecs::schedule::SystemSet;
+use std::collections::HashMap;
 
 use crate::benchmark::runner::SpawnEntitiesRequest;
-use crate::components::FastRng;
+use crate::components::{
+    Acceleration, Counter, DropPayload, EntityVariant, FastRng, Health, Position, Stats,
+    ToggleComponent, VariablePayload, Velocity,
+};
+use crate::config::{BenchmarkConfig, TimingSource, DEFAULT_RNG_SEED};
+use crate::metrics::FrameMetrics;
 use crate::state::{AppState, SelectedWorkload};
 
 // =============================================================================
@@ -54,13 +65,179 @@ pub enum BenchmarkSet {
     Process,
 }
 
+/// Work units per entity for each workload, used to put throughput on a
+/// common scale (e.g. reading 3 components counts as 3 units per entity).
+///
+/// Filled in by `WorkloadsPlugin` next to the system registrations;
+/// workloads without an entry weigh 1.0.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct WorkloadWeights(pub HashMap<SelectedWorkload, f64>);
+
+impl WorkloadWeights {
+    pub fn with(mut self, workload: SelectedWorkload, weight: f64) -> Self {
+        self.0.insert(workload, weight);
+        self
+    }
+
+    pub fn weight(&self, workload: SelectedWorkload) -> f64 {
+        self.0.get(&workload).copied().unwrap_or(1.0)
+    }
+
+    /// Weight for `workload` as currently configured: the components read
+    /// for Multi-Component Read, the fixed weight otherwise
+    pub fn configured_weight(&self, workload: SelectedWorkload, config: &BenchmarkConfig) -> f64 {
+        match workload {
+            SelectedWorkload::MultiComponentRead => config.multi_component_count as f64,
+            SelectedWorkload::RunConditionOverhead => config.run_condition_passing_systems() as f64,
+            _ => self.weight(workload),
+        }
+    }
+}
+
+/// Sum of `size_of` over a list of component types
+macro_rules! component_bytes {
+    ($($component:ty),+ $(,)?) => {
+        0 $(+ std::mem::size_of::<$component>())+
+    };
+}
+
+/// Inline bytes each workload stores per entity: the `size_of` sum of the
+/// data components it spawns (markers are zero-sized; heap data such as a
+/// `Vec`'s contents isn't counted).
+///
+/// Filled in by `WorkloadsPlugin` next to the system registrations;
+/// workloads without an entry count 0 bytes.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct WorkloadFootprints(pub HashMap<SelectedWorkload, usize>);
+
+impl WorkloadFootprints {
+    pub fn with(mut self, workload: SelectedWorkload, bytes: usize) -> Self {
+        self.0.insert(workload, bytes);
+        self
+    }
+
+    pub fn bytes_per_entity(&self, workload: SelectedWorkload) -> usize {
+        self.0.get(&workload).copied().unwrap_or(0)
+    }
+}
+
 /// Plugin that registers all workload systems
 pub struct WorkloadsPlugin;
 
 impl Plugin for WorkloadsPlugin {
     fn build(&self, app: &mut App) {
-        // Initialize FastRng resource for optimized random number generation
-        app.init_resource::<FastRng>();
+        // Initialize FastRng resource for optimized random number generation,
+        // using the configured generator
+        let rng_backend = app.world().resource::<BenchmarkConfig>().rng_backend;
+        app.insert_resource(FastRng::with_backend(rng_backend, DEFAULT_RNG_SEED));
+        app.init_resource::<RandomAccessOrder>();
+        app.init_resource::<BulkDespawnState>();
+        app.init_resource::<DisjointSpans>();
+        app.init_resource::<DisjointOverlap>();
+        app.init_resource::<DefragRecoveryState>();
+        app.init_resource::<IterationSink>();
+        app.init_resource::<EntityLookupTable>();
+        init_contention_resources(app);
+        init_many_systems(app);
+        init_run_condition_systems(app);
+
+        // Component accesses per entity per frame in each workload's
+        // process systems (structural workloads count one op per entity).
+        // Multi-Component Read's weight follows `multi_component_count` and
+        // Run Condition Overhead's the number of passing conditions.
+        let config = app.world().resource::<BenchmarkConfig>();
+        let weights = WorkloadWeights::default()
+            .with(SelectedWorkload::PositionVelocity, 2.0)
+            .with(SelectedWorkload::FragmentedArchetypes, 2.0)
+            .with(SelectedWorkload::EntityIdIteration, 2.0)
+            .with(SelectedWorkload::SpriteRender, 2.0)
+            .with(SelectedWorkload::DisjointParallel, 2.0)
+            .with(SelectedWorkload::IterStyle, 2.0)
+            .with(SelectedWorkload::DefragRecovery, 2.0)
+            .with(
+                SelectedWorkload::ManySystems,
+                config.many_systems_count as f64,
+            );
+        app.insert_resource(weights);
+
+        // Component set each workload's spawn systems give every entity
+        let position_velocity = component_bytes!(Position, Velocity);
+        let footprints = WorkloadFootprints::default()
+            .with(SelectedWorkload::SimpleIteration, component_bytes!(Counter))
+            .with(
+                SelectedWorkload::MultiComponentRead,
+                component_bytes!(Position, Velocity, Acceleration, Health, Stats, Counter),
+            )
+            .with(SelectedWorkload::PositionVelocity, position_velocity)
+            .with(SelectedWorkload::SpawnDespawn, position_velocity)
+            .with(
+                SelectedWorkload::ComponentAddRemove,
+                component_bytes!(Counter, ToggleComponent),
+            )
+            .with(SelectedWorkload::FragmentedArchetypes, position_velocity)
+            .with(SelectedWorkload::OrFilter, component_bytes!(Position))
+            .with(SelectedWorkload::AddedDetection, position_velocity)
+            .with(
+                SelectedWorkload::DropHeavy,
+                component_bytes!(Position, DropPayload),
+            )
+            .with(
+                SelectedWorkload::VariablePayload,
+                component_bytes!(VariablePayload),
+            )
+            .with(SelectedWorkload::IncrementalInsert, position_velocity)
+            .with(
+                SelectedWorkload::PositionIteration,
+                component_bytes!(Position),
+            )
+            .with(
+                SelectedWorkload::EntityIdIteration,
+                component_bytes!(Position),
+            )
+            .with(SelectedWorkload::RandomAccess, component_bytes!(Position))
+            .with(
+                SelectedWorkload::SpriteRender,
+                component_bytes!(Sprite, Transform, Velocity),
+            )
+            .with(SelectedWorkload::ManySystems, component_bytes!(Counter))
+            .with(
+                SelectedWorkload::RunConditionOverhead,
+                component_bytes!(Counter),
+            )
+            .with(SelectedWorkload::CombinedQuery, position_velocity)
+            .with(SelectedWorkload::QueryTransmute, position_velocity)
+            .with(SelectedWorkload::ChunkedSpawn, position_velocity)
+            .with(
+                SelectedWorkload::BitflagBranching,
+                component_bytes!(Position, Velocity, EntityVariant),
+            )
+            .with(SelectedWorkload::ParallelPrepSpawn, position_velocity)
+            .with(
+                SelectedWorkload::RecursiveDespawn,
+                component_bytes!(Counter, ChildOf),
+            )
+            .with(SelectedWorkload::ExclusiveWorld, position_velocity)
+            .with(SelectedWorkload::LocalVsRes, position_velocity)
+            .with(
+                SelectedWorkload::PairwiseCombinations,
+                component_bytes!(Position),
+            )
+            .with(SelectedWorkload::BulkDespawn, position_velocity)
+            .with(
+                SelectedWorkload::DisjointParallel,
+                component_bytes!(Position, Counter),
+            )
+            .with(
+                SelectedWorkload::IterStyle,
+                component_bytes!(Position, Velocity, Acceleration),
+            )
+            .with(SelectedWorkload::DefragRecovery, position_velocity)
+            // The side table holds one (Entity, usize) entry per entity too
+            .with(
+                SelectedWorkload::EntityMapLookup,
+                component_bytes!(Counter, (Entity, usize)),
+            );
+        app.insert_resource(footprints);
 
         // Configure system set ordering: Spawn → Process
         // This ensures entities exist before systems try to iterate them
@@ -69,6 +246,19 @@ impl Plugin for WorkloadsPlugin {
             (BenchmarkSet::Spawn, BenchmarkSet::Process).chain(),
         );
 
+        // Optional Instant-based timing around both sets. The auto-inserted
+        // sync point before `end_workload_span` means deferred commands from
+        // the workload systems are included in the span.
+        app.add_systems(
+            Update,
+            (
+                begin_workload_span.before(BenchmarkSet::Spawn),
+                end_workload_span.after(BenchmarkSet::Process),
+            )
+                .run_if(uses_workload_span)
+                .run_if(in_state(AppState::Running)),
+        );
+
         app
             // =================================================================
             // Iteration workloads
@@ -82,21 +272,27 @@ impl Plugin for WorkloadsPlugin {
             )
             .add_systems(
                 Update,
-                simple_iteration_system
+                (
+                    simple_iteration_system::<true>.run_if(black_box_reads_enabled),
+                    simple_iteration_system::<false>.run_if(not(black_box_reads_enabled)),
+                )
                     .in_set(BenchmarkSet::Process)
                     .run_if(resource_equals(SelectedWorkload::SimpleIteration))
                     .run_if(in_state(AppState::Running)),
             )
             .add_systems(
                 Update,
-                spawn_multi_component_entities
+                spawn_wide_component_entities
                     .in_set(BenchmarkSet::Spawn)
                     .run_if(resource_equals(SelectedWorkload::MultiComponentRead))
                     .run_if(in_state(AppState::Running)),
             )
             .add_systems(
                 Update,
-                multi_component_read_system
+                (
+                    multi_component_read_system::<true>.run_if(black_box_reads_enabled),
+                    multi_component_read_system::<false>.run_if(not(black_box_reads_enabled)),
+                )
                     .in_set(BenchmarkSet::Process)
                     .run_if(resource_equals(SelectedWorkload::MultiComponentRead))
                     .run_if(in_state(AppState::Running)),
@@ -165,10 +361,570 @@ impl Plugin for WorkloadsPlugin {
                     .in_set(BenchmarkSet::Process)
                     .run_if(resource_equals(SelectedWorkload::FragmentedArchetypes))
                     .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                spawn_or_filter_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::OrFilter))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                or_filter_query_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::OrFilter))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Change detection workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_despawn_setup
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::AddedDetection))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                spawn_despawn_churn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::AddedDetection))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                added_detection_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::AddedDetection))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Destructor cost workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_drop_heavy_setup
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::DropHeavy))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                drop_heavy_churn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::DropHeavy))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Resource contention workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_contention_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(
+                        resource_equals(SelectedWorkload::ResourceContention)
+                            .or(resource_equals(SelectedWorkload::ResourceParallel)),
+                    )
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    shared_resource_system::<0>,
+                    shared_resource_system::<1>,
+                    shared_resource_system::<2>,
+                    shared_resource_system::<3>,
+                    shared_resource_system::<4>,
+                    shared_resource_system::<5>,
+                    shared_resource_system::<6>,
+                    shared_resource_system::<7>,
+                )
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::ResourceContention))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    distinct_resource_system::<0>,
+                    distinct_resource_system::<1>,
+                    distinct_resource_system::<2>,
+                    distinct_resource_system::<3>,
+                    distinct_resource_system::<4>,
+                    distinct_resource_system::<5>,
+                    distinct_resource_system::<6>,
+                    distinct_resource_system::<7>,
+                )
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::ResourceParallel))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Payload size workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_variable_payload_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::VariablePayload))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                variable_payload_read_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::VariablePayload))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Incremental insert workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_incremental_insert_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::IncrementalInsert))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                incremental_insert_churn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::IncrementalInsert))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Entity id iteration workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_position_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::PositionIteration))
+                    .run_if(not(sparse_storage_enabled))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    position_iteration_system::<true>.run_if(black_box_reads_enabled),
+                    position_iteration_system::<false>.run_if(not(black_box_reads_enabled)),
+                )
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::PositionIteration))
+                    .run_if(not(sparse_storage_enabled))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                spawn_position_sparse_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::PositionIteration))
+                    .run_if(sparse_storage_enabled)
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    position_sparse_iteration_system::<true>.run_if(black_box_reads_enabled),
+                    position_sparse_iteration_system::<false>.run_if(not(black_box_reads_enabled)),
+                )
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::PositionIteration))
+                    .run_if(sparse_storage_enabled)
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                spawn_position_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::EntityIdIteration))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (
+                    entity_id_iteration_system::<true>.run_if(black_box_reads_enabled),
+                    entity_id_iteration_system::<false>.run_if(not(black_box_reads_enabled)),
+                )
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::EntityIdIteration))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Random access workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_random_access_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::RandomAccess))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                random_access_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::RandomAccess))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Render workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_sprite_render_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::SpriteRender))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                sprite_movement_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::SpriteRender))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Scheduler overhead workload (systems registered by init_many_systems)
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_many_systems_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::ManySystems))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Query lens workloads
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_position_velocity_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::CombinedQuery))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                combined_query_read_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::CombinedQuery))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                spawn_position_velocity_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::QueryTransmute))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                query_transmute_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::QueryTransmute))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Chunked spawn workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_chunked_setup
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::ChunkedSpawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                chunked_spawn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::ChunkedSpawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Bitflag branching workload (B toggles the sub-variant)
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_unified_variant_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::BitflagBranching))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                bitflag_branch_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::BitflagBranching))
+                    .run_if(bitflag_checks_enabled)
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                bitflag_no_check_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::BitflagBranching))
+                    .run_if(not(bitflag_checks_enabled))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Parallel prep spawn workload (P toggles parallel/sequential prep)
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_parallel_prep_setup
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::ParallelPrepSpawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                parallel_prep_spawn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::ParallelPrepSpawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Recursive despawn workload (H cycles tree depth)
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_hierarchy_setup
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::RecursiveDespawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                recursive_despawn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::RecursiveDespawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Exclusive world churn workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_despawn_setup
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::ExclusiveWorld))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                exclusive_world_churn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::ExclusiveWorld))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Local vs Res workload (L toggles the sub-variant)
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_position_velocity_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::LocalVsRes))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                local_cached_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::LocalVsRes))
+                    .run_if(local_cache_enabled)
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                res_recompute_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::LocalVsRes))
+                    .run_if(not(local_cache_enabled))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Pairwise combinations workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_position_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::PairwiseCombinations))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                pairwise_distance_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::PairwiseCombinations))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Bulk despawn workload
+            // =================================================================
+            .add_systems(
+                Update,
+                bulk_despawn_setup
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::BulkDespawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                bulk_despawn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::BulkDespawn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Disjoint parallel workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_disjoint_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::DisjointParallel))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (disjoint_read_system, disjoint_write_system)
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::DisjointParallel))
+                    .run_if(not(disjoint_chained))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (disjoint_read_system, disjoint_write_system)
+                    .chain()
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::DisjointParallel))
+                    .run_if(disjoint_chained)
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                record_disjoint_overlap
+                    .after(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::DisjointParallel))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Iteration style workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_multi_component_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::IterStyle))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                for_each_style_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::IterStyle))
+                    .run_if(iter_for_each_enabled)
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                for_loop_style_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::IterStyle))
+                    .run_if(not(iter_for_each_enabled))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Resource churn workload (fixed cost)
+            // =================================================================
+            .add_systems(
+                Update,
+                resource_churn_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::ResourceChurn))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Defrag recovery workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_defrag_recovery_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::DefragRecovery))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                (defrag_iteration_system, defragment_system)
+                    .chain()
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::DefragRecovery))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Entity map lookup workload
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_entity_map_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::EntityMapLookup))
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                entity_map_lookup_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::EntityMapLookup))
+                    .run_if(entity_map_enabled)
+                    .run_if(in_state(AppState::Running)),
+            )
+            .add_systems(
+                Update,
+                entity_map_direct_system
+                    .in_set(BenchmarkSet::Process)
+                    .run_if(resource_equals(SelectedWorkload::EntityMapLookup))
+                    .run_if(not(entity_map_enabled))
+                    .run_if(in_state(AppState::Running)),
+            )
+            // =================================================================
+            // Run condition overhead workload (systems registered by init_run_condition_systems)
+            // =================================================================
+            .add_systems(
+                Update,
+                spawn_many_systems_entities
+                    .in_set(BenchmarkSet::Spawn)
+                    .run_if(resource_equals(SelectedWorkload::RunConditionOverhead))
+                    .run_if(in_state(AppState::Running)),
             );
     }
 }
 
+/// Run condition: `TimingSource::WorkloadSpan` is selected
+fn uses_workload_span(config: Res<BenchmarkConfig>) -> bool {
+    config.timing == TimingSource::WorkloadSpan
+}
+
+/// Start timing the benchmark systems for this frame
+fn begin_workload_span(mut metrics: ResMut<FrameMetrics>) {
+    metrics.begin_span();
+}
+
+/// Stop timing the benchmark systems for this frame
+fn end_workload_span(mut metrics: ResMut<FrameMetrics>) {
+    metrics.end_span();
+}
+
 /// Helper to check which entities need spawning
 fn needs_spawn(
     spawn_events: &mut MessageReader<SpawnEntitiesRequest>,
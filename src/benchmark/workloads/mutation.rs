@@ -14,6 +14,8 @@ use std::hint::black_box;
 
 use crate::benchmark::runner::SpawnEntitiesRequest;
 use crate::components::{BenchmarkEntity, Counter, DataPayload, FastRng, Position, Velocity};
+use crate::config::BenchmarkConfig;
+use crate::metrics::ParIterProbe;
 
 // =============================================================================
 // Position/Velocity Update Workload
@@ -85,23 +87,42 @@ pub fn spawn_position_velocity_entities(
 pub fn position_velocity_system(
     mut query: Query<(&mut Position, &Velocity), With<BenchmarkEntity>>,
     time: Res<Time>,
+    probe: Res<ParIterProbe>,
 ) {
     // Direct resource access - Res<T> is just a pointer dereference
     let dt = time.delta_secs();
 
+    // One instrumented frame per search iteration records how the work
+    // was split up (see `ParIterProbe`)
+    if probe.is_armed() {
+        query.par_iter_mut().for_each_init(
+            || probe.begin_task(),
+            |_, (mut pos, vel)| {
+                probe.record();
+                integrate_position(&mut pos, vel, dt);
+            },
+        );
+        return;
+    }
+
     // Parallel iteration distributes entities across worker threads
     query.par_iter_mut().for_each(|(mut pos, vel)| {
-        pos.x += vel.x * dt;
-        pos.y += vel.y * dt;
-        pos.z += vel.z * dt;
-
-        // Keep values bounded to prevent float issues over long runs
-        pos.x = pos.x.rem_euclid(2000.0) - 1000.0;
-        pos.y = pos.y.rem_euclid(2000.0) - 1000.0;
-        pos.z = pos.z.rem_euclid(2000.0) - 1000.0;
+        integrate_position(&mut pos, vel, dt);
     });
 }
 
+/// Move by velocity, wrapping to keep values bounded over long runs
+#[inline]
+fn integrate_position(pos: &mut Position, vel: &Velocity, dt: f32) {
+    pos.x += vel.x * dt;
+    pos.y += vel.y * dt;
+    pos.z += vel.z * dt;
+
+    pos.x = pos.x.rem_euclid(2000.0) - 1000.0;
+    pos.y = pos.y.rem_euclid(2000.0) - 1000.0;
+    pos.z = pos.z.rem_euclid(2000.0) - 1000.0;
+}
+
 // =============================================================================
 // Counter Increment Workload
 // =============================================================================
@@ -201,7 +222,21 @@ pub fn spawn_heavy_mutation_entities(
 ///
 /// The cache-aligned DataPayload (64 bytes, aligned to cache line)
 /// combined with parallel iteration minimizes false sharing between threads.
-pub fn heavy_mutation_system(mut query: Query<&mut DataPayload, With<BenchmarkEntity>>) {
+pub fn heavy_mutation_system(
+    mut query: Query<&mut DataPayload, With<BenchmarkEntity>>,
+    probe: Res<ParIterProbe>,
+) {
+    if probe.is_armed() {
+        query.par_iter_mut().for_each_init(
+            || probe.begin_task(),
+            |_, mut payload| {
+                probe.record();
+                payload.process();
+            },
+        );
+        return;
+    }
+
     query.par_iter_mut().for_each(|mut payload| {
         payload.process();
     });
@@ -218,17 +253,23 @@ pub fn heavy_mutation_system(mut query: Query<&mut DataPayload, With<BenchmarkEn
 pub fn parallel_position_update_system(
     mut query: Query<(&mut Position, &Velocity), With<BenchmarkEntity>>,
     time: Res<Time>,
+    probe: Res<ParIterProbe>,
 ) {
     let dt = time.delta_secs();
 
-    query.par_iter_mut().for_each(|(mut pos, vel)| {
-        pos.x += vel.x * dt;
-        pos.y += vel.y * dt;
-        pos.z += vel.z * dt;
+    if probe.is_armed() {
+        query.par_iter_mut().for_each_init(
+            || probe.begin_task(),
+            |_, (mut pos, vel)| {
+                probe.record();
+                integrate_position(&mut pos, vel, dt);
+            },
+        );
+        return;
+    }
 
-        pos.x = pos.x.rem_euclid(2000.0) - 1000.0;
-        pos.y = pos.y.rem_euclid(2000.0) - 1000.0;
-        pos.z = pos.z.rem_euclid(2000.0) - 1000.0;
+    query.par_iter_mut().for_each(|(mut pos, vel)| {
+        integrate_position(&mut pos, vel, dt);
     });
 }
 
@@ -278,3 +319,65 @@ pub fn read_modify_write_system(
     // Prevent optimization
     black_box(());
 }
+
+// =============================================================================
+// Local vs Res Workload
+// =============================================================================
+
+/// Fraction of velocity kept after one second in the Local vs Res workload
+const VELOCITY_RETAINED_PER_SEC: f32 = 0.5;
+
+/// Run condition: Local vs Res is measuring the `Local<T>` cache
+pub fn local_cache_enabled(config: Res<BenchmarkConfig>) -> bool {
+    config.local_cache
+}
+
+/// Damped position update shared by both Local vs Res systems
+fn integrate_damped(
+    query: &mut Query<(&mut Position, &Velocity), With<BenchmarkEntity>>,
+    dt: f32,
+    damping: f32,
+) {
+    let scale = dt * damping;
+    query.par_iter_mut().for_each(|(mut pos, vel)| {
+        pos.x = (pos.x + vel.x * scale).rem_euclid(2000.0) - 1000.0;
+        pos.y = (pos.y + vel.y * scale).rem_euclid(2000.0) - 1000.0;
+        pos.z = (pos.z + vel.z * scale).rem_euclid(2000.0) - 1000.0;
+    });
+}
+
+/// Recompute the damping factor from `Res<Time>` on every run.
+///
+/// The `powf` happens once per system run, not per entity, so its cost is
+/// fixed regardless of entity count.
+pub fn res_recompute_system(
+    mut query: Query<(&mut Position, &Velocity), With<BenchmarkEntity>>,
+    time: Res<Time>,
+) {
+    let dt = time.delta_secs();
+    let damping = black_box(VELOCITY_RETAINED_PER_SEC.powf(dt));
+    integrate_damped(&mut query, dt, damping);
+}
+
+/// Same work, caching the damping factor in `Local<T>` keyed on the delta.
+///
+/// The delta changes almost every frame, so the cache rarely hits and the
+/// `Local` check is pure overhead - matching breakdown points show that
+/// caching a cheap per-frame value buys nothing (see
+/// `position_velocity_system` for when `Local` does help).
+pub fn local_cached_system(
+    mut query: Query<(&mut Position, &Velocity), With<BenchmarkEntity>>,
+    time: Res<Time>,
+    mut cache: Local<Option<(f32, f32)>>,
+) {
+    let dt = time.delta_secs();
+    let damping = match *cache {
+        Some((cached_dt, damping)) if cached_dt == dt => damping,
+        _ => {
+            let damping = black_box(VELOCITY_RETAINED_PER_SEC.powf(dt));
+            *cache = Some((dt, damping));
+            damping
+        }
+    };
+    integrate_damped(&mut query, dt, damping);
+}
@@ -0,0 +1,83 @@
+//! Render-inclusive workloads.
+//!
+//! Unlike the other workloads, these spawn entities that the renderer
+//! extracts and draws every frame, so the breakdown point reflects the
+//! combined ECS + render pipeline budget rather than pure ECS cost.
+//!
+//! Only registered through `WorkloadsPlugin`, which requires the windowed
+//! app; the headless `BenchmarkRunner` has no renderer to exercise.
+
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::benchmark::runner::SpawnEntitiesRequest;
+use crate::components::{BenchmarkEntity, FastRng, Velocity};
+
+/// Half-width of the area sprites move within (world units)
+const SPRITE_AREA_HALF_WIDTH: f32 = 600.0;
+
+/// Half-height of the area sprites move within (world units)
+const SPRITE_AREA_HALF_HEIGHT: f32 = 350.0;
+
+/// Edge length of each sprite (pixels)
+const SPRITE_SIZE: f32 = 4.0;
+
+// =============================================================================
+// Sprite Render Workload
+// =============================================================================
+
+/// Spawn small colored sprites at random positions with random velocities.
+///
+/// Sprites are drawn beneath the dashboard's opaque UI, but they are still
+/// extracted, batched and drawn each frame - that cost is what this
+/// workload measures.
+pub fn spawn_sprite_render_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} sprites", event.count);
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|_| {
+                let rng = &mut rng.0;
+                let x = rng.gen_range(-SPRITE_AREA_HALF_WIDTH..SPRITE_AREA_HALF_WIDTH);
+                let y = rng.gen_range(-SPRITE_AREA_HALF_HEIGHT..SPRITE_AREA_HALF_HEIGHT);
+                let color = Color::hsl(rng.gen_range(0.0..360.0), 0.7, 0.6);
+                (
+                    BenchmarkEntity,
+                    Sprite::from_color(color, Vec2::splat(SPRITE_SIZE)),
+                    Transform::from_xyz(x, y, 0.0),
+                    Velocity::random_with(rng),
+                )
+            })
+            .collect();
+
+        commands.spawn_batch(entities);
+    }
+}
+
+/// Move sprites by their velocity, bouncing off the edges of the area.
+///
+/// Changing `Transform` every frame forces transform propagation and
+/// re-extraction of every sprite.
+pub fn sprite_movement_system(
+    time: Res<Time>,
+    mut query: Query<(&mut Transform, &mut Velocity), (With<Sprite>, With<BenchmarkEntity>)>,
+) {
+    // Velocity components are in the ±10 range; scale up to pixels/second
+    let dt = time.delta_secs() * 10.0;
+
+    query.par_iter_mut().for_each(|(mut transform, mut vel)| {
+        transform.translation.x += vel.x * dt;
+        transform.translation.y += vel.y * dt;
+
+        if transform.translation.x.abs() > SPRITE_AREA_HALF_WIDTH {
+            vel.x = -vel.x;
+        }
+        if transform.translation.y.abs() > SPRITE_AREA_HALF_HEIGHT {
+            vel.y = -vel.y;
+        }
+    });
+}
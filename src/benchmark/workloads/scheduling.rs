@@ -0,0 +1,274 @@
+//! Scheduler overhead workloads.
+//!
+//! Registers many trivial systems with constant-cost bodies, so the frame
+//! time is per-system scheduling cost (run condition checks, executor
+//! bookkeeping, task dispatch) rather than per-entity work.
+
+use bevy::ecs::schedule::ScheduleLabel;
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use std::hint::black_box;
+use std::sync::Mutex;
+use std::thread::{self, ThreadId};
+
+use crate::benchmark::runner::SpawnEntitiesRequest;
+use crate::benchmark::workloads::BenchmarkSet;
+use crate::components::{BenchmarkEntity, Counter, FastRng, Position};
+use crate::config::{BenchmarkConfig, RUN_CONDITION_PASS_EVERY, TRIVIAL_SYSTEM_READS};
+use crate::state::{AppState, SelectedWorkload};
+
+/// Set containing every trivial system, so the run conditions are
+/// evaluated once for the whole group rather than once per system
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ManySystemsSet;
+
+/// Register `BenchmarkConfig::many_systems_count` copies of `trivial_system`.
+///
+/// Systems can't be added once the app is running, so the count is read
+/// from the config at plugin build time. Insert a customized
+/// `BenchmarkConfig` before adding the plugins to change it.
+pub fn init_many_systems(app: &mut App) {
+    let count = many_systems_count(app);
+
+    app.configure_sets(
+        Update,
+        ManySystemsSet
+            .in_set(BenchmarkSet::Process)
+            .run_if(resource_equals(SelectedWorkload::ManySystems))
+            .run_if(in_state(AppState::Running)),
+    );
+
+    for _ in 0..count {
+        app.add_systems(Update, trivial_system.in_set(ManySystemsSet));
+    }
+
+    info!(
+        "Registered {} trivial systems for the many systems workload",
+        count
+    );
+}
+
+/// `many_systems_count` from the app's config, or the default if none was inserted
+fn many_systems_count(app: &App) -> usize {
+    app.world()
+        .get_resource::<BenchmarkConfig>()
+        .map(|config| config.many_systems_count)
+        .unwrap_or_else(|| BenchmarkConfig::default().many_systems_count)
+}
+
+// =============================================================================
+// Many Systems Workload
+// =============================================================================
+
+/// Spawn entities with a single Counter for the trivial systems to read
+pub fn spawn_many_systems_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} entities for many systems", event.count);
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|_| (BenchmarkEntity, Counter::default()))
+            .collect();
+
+        commands.spawn_batch(entities);
+    }
+}
+
+/// Read of the first `TRIVIAL_SYSTEM_READS` Counters, one of many identical
+/// copies.
+///
+/// The body is O(1), so the frame time is per-system scheduling overhead
+/// rather than N passes over every entity. Re-run with different
+/// `many_systems_count` values to see how it scales.
+pub fn trivial_system(query: Query<&Counter, With<BenchmarkEntity>>) {
+    let mut sum: u64 = 0;
+    for counter in query.iter().take(TRIVIAL_SYSTEM_READS) {
+        sum = sum.wrapping_add(black_box(counter.value));
+    }
+    black_box(sum);
+}
+
+// =============================================================================
+// Run Condition Overhead Workload
+// =============================================================================
+
+/// Schedule holding the guarded systems, run from `BenchmarkSet::Process`
+/// only while Run Condition Overhead is selected.
+///
+/// A failing set condition doesn't stop the executor from evaluating the
+/// systems' own conditions, so in `Update` every other workload would pay
+/// for all of them each frame.
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RunConditionSchedule;
+
+/// Register `BenchmarkConfig::many_systems_count` copies of `trivial_system`
+/// in `RunConditionSchedule`, each behind its own run condition.
+///
+/// The `index`th condition reads the config and passes only when `index` is
+/// a multiple of `RUN_CONDITION_PASS_EVERY` (or when `run_conditions_pass`
+/// is set), so most systems are skipped after evaluating their condition.
+/// The gap between the two sub-variants is the cost of the skipped systems'
+/// condition checks.
+pub fn init_run_condition_systems(app: &mut App) {
+    let count = many_systems_count(app);
+
+    app.add_schedule(Schedule::new(RunConditionSchedule))
+        .add_systems(
+            Update,
+            run_condition_schedule
+                .in_set(BenchmarkSet::Process)
+                .run_if(resource_equals(SelectedWorkload::RunConditionOverhead))
+                .run_if(in_state(AppState::Running)),
+        );
+
+    for index in 0..count {
+        app.add_systems(
+            RunConditionSchedule,
+            trivial_system.run_if(move |config: Res<BenchmarkConfig>| {
+                config.run_conditions_pass || index % RUN_CONDITION_PASS_EVERY == 0
+            }),
+        );
+    }
+
+    info!(
+        "Registered {} condition-guarded systems for the run condition overhead workload",
+        count
+    );
+}
+
+/// Run the condition-guarded systems
+fn run_condition_schedule(world: &mut World) {
+    world.run_schedule(RunConditionSchedule);
+}
+
+// =============================================================================
+// Disjoint Parallel Workload
+// =============================================================================
+
+/// Run condition: Disjoint Parallel is measuring the chained variant
+pub fn disjoint_chained(config: Res<BenchmarkConfig>) -> bool {
+    config.disjoint_chained
+}
+
+/// Spawn entities with both components the disjoint systems touch
+pub fn spawn_disjoint_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut rng: ResMut<FastRng>,
+    mut overlap: ResMut<DisjointOverlap>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} entities for disjoint parallel", event.count);
+        *overlap = DisjointOverlap::default();
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|_| {
+                (
+                    BenchmarkEntity,
+                    Position::random_with(&mut rng.0),
+                    Counter::default(),
+                )
+            })
+            .collect();
+
+        commands.spawn_batch(entities);
+    }
+}
+
+/// When and where one disjoint system ran this frame
+#[derive(Debug, Clone, Copy)]
+pub struct SystemSpan {
+    pub thread: ThreadId,
+    pub start: Instant,
+    pub end: Instant,
+}
+
+impl SystemSpan {
+    /// Ran on another thread during an overlapping time window
+    pub fn ran_alongside(&self, other: &SystemSpan) -> bool {
+        self.thread != other.thread && self.start < other.end && other.start < self.end
+    }
+}
+
+/// Spans written by the two disjoint systems.
+///
+/// Read through `Res` with interior mutability: taking `ResMut` in both
+/// systems would be a conflict and force the scheduler to serialize them,
+/// which is exactly what the workload is checking for.
+#[derive(Resource, Default)]
+pub struct DisjointSpans {
+    pub read: Mutex<Option<SystemSpan>>,
+    pub write: Mutex<Option<SystemSpan>>,
+}
+
+/// Frames where the two disjoint systems ran in parallel, since the last spawn
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct DisjointOverlap {
+    pub frames: usize,
+    pub overlapped_frames: usize,
+}
+
+impl DisjointOverlap {
+    /// Fraction of frames where both systems ran at once (None before any frame)
+    pub fn fraction(&self) -> Option<f64> {
+        (self.frames > 0).then(|| self.overlapped_frames as f64 / self.frames as f64)
+    }
+}
+
+fn store_span(slot: &Mutex<Option<SystemSpan>>, start: Instant) {
+    let span = SystemSpan {
+        thread: thread::current().id(),
+        start,
+        end: Instant::now(),
+    };
+    *slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(span);
+}
+
+/// Read-only pass over `Position`; shares no data with `disjoint_write_system`
+pub fn disjoint_read_system(
+    query: Query<&Position, With<BenchmarkEntity>>,
+    spans: Res<DisjointSpans>,
+) {
+    let start = Instant::now();
+    let mut sum: f32 = 0.0;
+    for pos in &query {
+        sum += black_box(pos.x + pos.y + pos.z);
+    }
+    black_box(sum);
+    store_span(&spans.read, start);
+}
+
+/// Write pass over `Counter`; shares no data with `disjoint_read_system`
+pub fn disjoint_write_system(
+    mut query: Query<&mut Counter, With<BenchmarkEntity>>,
+    spans: Res<DisjointSpans>,
+) {
+    let start = Instant::now();
+    for mut counter in &mut query {
+        counter.increment();
+    }
+    store_span(&spans.write, start);
+}
+
+/// Count the frames where both disjoint systems ran at once.
+///
+/// Heuristic: different threads with overlapping time windows. Unordered
+/// systems that don't conflict should overlap on most frames once there is
+/// enough work per system; the chained variant never should.
+pub fn record_disjoint_overlap(spans: Res<DisjointSpans>, mut overlap: ResMut<DisjointOverlap>) {
+    let take = |slot: &Mutex<Option<SystemSpan>>| {
+        slot.lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+    };
+    let (Some(read), Some(write)) = (take(&spans.read), take(&spans.write)) else {
+        return;
+    };
+
+    overlap.frames += 1;
+    if read.ran_alongside(&write) {
+        overlap.overlapped_frames += 1;
+    }
+}
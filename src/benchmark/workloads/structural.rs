@@ -8,12 +8,22 @@
 //! - **FastRng for bulk operations**: Avoid thread_rng() overhead
 //! - **Efficient despawning**: Process despawn commands in batches
 
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
+use bevy::tasks::ComputeTaskPool;
+use rand::{Rng, SeedableRng};
+use rand_xoshiro::Xoshiro256PlusPlus;
+use std::hint::black_box;
+use std::time::Duration;
 
 use crate::benchmark::runner::SpawnEntitiesRequest;
 use crate::components::{
-    BenchmarkEntity, Counter, FastRng, Position, SecondaryToggle, ToggleComponent, Velocity,
+    BenchmarkEntity, Counter, DropPayload, FastRng, Position, SecondaryToggle, ToggleComponent,
+    Velocity,
 };
+use crate::config::{BenchmarkConfig, HIERARCHY_FANOUT};
+use crate::metrics::FrameMetrics;
+use crate::state::{BenchmarkPhase, SelectedWorkload};
 
 // =============================================================================
 // Spawn/Despawn Churn Workload
@@ -36,12 +46,13 @@ pub fn spawn_despawn_setup(
     mut spawn_events: MessageReader<SpawnEntitiesRequest>,
     mut state: ResMut<SpawnDespawnState>,
     mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
 ) {
     if let Some(event) = spawn_events.read().last() {
         info!("Setting up spawn/despawn churn with {} entities", event.count);
 
         state.target_count = event.count;
-        state.churn_rate = 0.01; // 1% churn per frame
+        state.churn_rate = config.churn_rate;
         state.initialized = true;
         state.current_count = 0;
 
@@ -87,6 +98,8 @@ pub fn spawn_despawn_churn_system(
     query: Query<Entity, With<BenchmarkEntity>>,
     mut rng: ResMut<FastRng>,
     mut despawn_buffer: Local<Vec<Entity>>,
+    mut metrics: ResMut<FrameMetrics>,
+    time: Res<Time>,
 ) {
     if !state.initialized {
         return;
@@ -99,6 +112,9 @@ pub fn spawn_despawn_churn_system(
     despawn_buffer.clear();
     despawn_buffer.extend(query.iter().take(churn_count));
 
+    // Every despawn is matched by a spawn
+    metrics.record_structural_ops(despawn_buffer.len() + churn_count, time.delta_secs_f64());
+
     // Despawn using drain() to consume while clearing
     for entity in despawn_buffer.drain(..) {
         commands.entity(entity).despawn();
@@ -118,6 +134,347 @@ pub fn spawn_despawn_churn_system(
     commands.spawn_batch(new_entities);
 }
 
+// =============================================================================
+// Exclusive World Churn Workload
+// =============================================================================
+
+/// Same churn as `spawn_despawn_churn_system`, applied directly to the World.
+///
+/// `Commands` record each despawn and spawn into a queue that is applied
+/// at the next sync point; an exclusive system skips the queue and mutates
+/// the World immediately, at the cost of running alone with no other
+/// system in parallel. Compare against Spawn/Despawn Churn at the same
+/// churn rate to see what the command queue costs.
+pub fn exclusive_world_churn_system(
+    world: &mut World,
+    query: &mut QueryState<Entity, With<BenchmarkEntity>>,
+    mut despawn_buffer: Local<Vec<Entity>>,
+) {
+    let state = world.resource::<SpawnDespawnState>();
+    if !state.initialized {
+        return;
+    }
+
+    let churn_count = ((state.target_count as f32) * state.churn_rate) as usize;
+    let churn_count = churn_count.max(10); // Minimum churn
+
+    despawn_buffer.clear();
+    despawn_buffer.extend(query.iter(world).take(churn_count));
+
+    let delta = world.resource::<Time>().delta_secs_f64();
+    world
+        .resource_mut::<FrameMetrics>()
+        .record_structural_ops(despawn_buffer.len() + churn_count, delta);
+
+    for entity in despawn_buffer.drain(..) {
+        world.despawn(entity);
+    }
+
+    let new_entities: Vec<_> = world.resource_scope(|_, mut rng: Mut<FastRng>| {
+        (0..churn_count)
+            .map(|_| {
+                (
+                    BenchmarkEntity,
+                    Position::random_with(&mut rng.0),
+                    Velocity::random_with(&mut rng.0),
+                )
+            })
+            .collect()
+    });
+
+    world.spawn_batch(new_entities);
+}
+
+// =============================================================================
+// Bulk Despawn Workload
+// =============================================================================
+
+/// Teardown size and timing for the bulk despawn workload
+#[derive(Resource, Default)]
+pub struct BulkDespawnState {
+    pub target_count: usize,
+    /// Teardown time summed over the sampled frames at the current count
+    pub teardown_total: Duration,
+    pub teardown_frames: u32,
+}
+
+impl BulkDespawnState {
+    /// Mean sampled teardown time in milliseconds at the current count
+    pub fn mean_teardown_ms(&self) -> Option<f64> {
+        (self.teardown_frames > 0)
+            .then(|| self.teardown_total.as_secs_f64() * 1000.0 / self.teardown_frames as f64)
+    }
+}
+
+/// Run condition: Bulk Despawn is measuring `World::clear_entities`
+pub fn bulk_despawn_enabled(config: Res<BenchmarkConfig>) -> bool {
+    config.bulk_despawn
+}
+
+/// Set the teardown size for the bulk despawn workload.
+///
+/// Nothing is spawned into the app's World: `bulk_despawn_system` builds
+/// and tears down its own scratch World, so `clear_entities` can't take the
+/// camera and UI with it. Logs the mean teardown time at the previous count.
+pub fn bulk_despawn_setup(
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut state: ResMut<BulkDespawnState>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        if let Some(ms) = state.mean_teardown_ms() {
+            info!(
+                "Bulk despawn: mean teardown {:.3}ms at {} entities",
+                ms, state.target_count
+            );
+        }
+        info!("Setting up bulk despawn with {} entities", event.count);
+
+        *state = BulkDespawnState {
+            target_count: event.count,
+            ..default()
+        };
+    }
+}
+
+/// Fill a scratch World with `target_count` entities, then tear it down.
+///
+/// The per-entity path queues `commands.entity(e).despawn()` for each
+/// entity and applies the queue, as `handle_despawn_requests` does. The
+/// bulk path is a single `World::clear_entities`, which drops every table
+/// without resolving entities one at a time. Only the teardown is timed,
+/// and only while sampling (cold warm-up frames would skew the mean); the
+/// rebuild is identical for both variants. The mean is recorded in the
+/// result as `teardown_ms`, since the frame time the search uses is
+/// dominated by the rebuild.
+pub fn bulk_despawn_system(
+    world: &mut World,
+    mut scratch: Local<World>,
+    mut entities: Local<Vec<Entity>>,
+) {
+    let target_count = world.resource::<BulkDespawnState>().target_count;
+    if target_count == 0 {
+        return;
+    }
+    let bulk = world.resource::<BenchmarkConfig>().bulk_despawn;
+
+    let new_entities: Vec<_> = world.resource_scope(|_, mut rng: Mut<FastRng>| {
+        (0..target_count)
+            .map(|_| {
+                (
+                    BenchmarkEntity,
+                    Position::random_with(&mut rng.0),
+                    Velocity::random_with(&mut rng.0),
+                )
+            })
+            .collect()
+    });
+    entities.clear();
+    entities.extend(scratch.spawn_batch(new_entities));
+
+    let start = Instant::now();
+    if bulk {
+        scratch.clear_entities();
+        entities.clear();
+    } else {
+        let mut commands = scratch.commands();
+        for entity in entities.drain(..) {
+            commands.entity(entity).despawn();
+        }
+        scratch.flush();
+    }
+    let teardown = start.elapsed();
+
+    if *world.resource::<State<BenchmarkPhase>>().get() == BenchmarkPhase::Sampling {
+        let mut state = world.resource_mut::<BulkDespawnState>();
+        state.teardown_total += teardown;
+        state.teardown_frames += 1;
+    }
+
+    let delta = world.resource::<Time>().delta_secs_f64();
+    world
+        .resource_mut::<FrameMetrics>()
+        .record_structural_ops(target_count * 2, delta);
+}
+
+// =============================================================================
+// Drop-Heavy Churn Workload
+// =============================================================================
+
+/// Initial spawn for the drop-heavy churn workload.
+///
+/// Same shape as `spawn_despawn_setup`, but every entity owns a heap
+/// allocation through `DropPayload`.
+pub fn spawn_drop_heavy_setup(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut state: ResMut<SpawnDespawnState>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Setting up drop-heavy churn with {} entities", event.count);
+
+        state.target_count = event.count;
+        state.churn_rate = config.churn_rate;
+        state.initialized = true;
+
+        let entities: Vec<_> = (0..event.count)
+            .map(|_| {
+                (
+                    BenchmarkEntity,
+                    Position::random_with(&mut rng.0),
+                    DropPayload::random_with(&mut rng.0),
+                )
+            })
+            .collect();
+
+        commands.spawn_batch(entities);
+        state.current_count = event.count;
+    }
+}
+
+/// Spawn/despawn churn where every despawn runs a destructor.
+///
+/// Compare the breakdown point against `SpawnDespawn`, which churns
+/// `Copy`-only `Position`/`Velocity` entities: the difference is the cost of
+/// allocating and freeing `DropPayload`'s `Vec` during structural changes.
+pub fn drop_heavy_churn_system(
+    mut commands: Commands,
+    state: Res<SpawnDespawnState>,
+    query: Query<Entity, With<BenchmarkEntity>>,
+    mut rng: ResMut<FastRng>,
+    mut despawn_buffer: Local<Vec<Entity>>,
+    mut metrics: ResMut<FrameMetrics>,
+    time: Res<Time>,
+) {
+    if !state.initialized {
+        return;
+    }
+
+    let churn_count = ((state.target_count as f32) * state.churn_rate) as usize;
+    let churn_count = churn_count.max(10); // Minimum churn
+
+    despawn_buffer.clear();
+    despawn_buffer.extend(query.iter().take(churn_count));
+
+    metrics.record_structural_ops(despawn_buffer.len() + churn_count, time.delta_secs_f64());
+
+    // Each despawn drops a DropPayload, freeing its Vec
+    for entity in despawn_buffer.drain(..) {
+        commands.entity(entity).despawn();
+    }
+
+    let new_entities: Vec<_> = (0..churn_count)
+        .map(|_| {
+            (
+                BenchmarkEntity,
+                Position::random_with(&mut rng.0),
+                DropPayload::random_with(&mut rng.0),
+            )
+        })
+        .collect();
+
+    commands.spawn_batch(new_entities);
+}
+
+// =============================================================================
+// Incremental Insert Workload
+// =============================================================================
+
+/// Initial spawn built the slow way: an empty entity plus one insert per component.
+///
+/// Each `insert` moves the entity to a new archetype (empty → marker →
+/// marker+Position → marker+Position+Velocity), copying its data every time.
+/// Compare against `spawn_position_velocity_entities`, which places the
+/// whole bundle in its final archetype in one step.
+pub fn spawn_incremental_insert_entities(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut state: ResMut<SpawnDespawnState>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!("Spawning {} entities via incremental insert", event.count);
+
+        state.target_count = event.count;
+        state.churn_rate = config.churn_rate;
+        state.initialized = true;
+
+        // NOTE: Deliberately NOT batched - the per-insert archetype moves are
+        // exactly what this workload measures.
+        for _ in 0..event.count {
+            commands
+                .spawn_empty()
+                .insert(BenchmarkEntity)
+                .insert(Position::random_with(&mut rng.0))
+                .insert(Velocity::random_with(&mut rng.0));
+        }
+
+        state.current_count = event.count;
+    }
+}
+
+/// Spawn/despawn churn where replacements are built with incremental inserts.
+///
+/// Mirrors `spawn_despawn_churn_system` (same churn rate, same components),
+/// so the gap between the two breakdown points is the cost of the extra
+/// archetype moves versus a single bundle spawn.
+pub fn incremental_insert_churn_system(
+    mut commands: Commands,
+    state: Res<SpawnDespawnState>,
+    query: Query<Entity, With<BenchmarkEntity>>,
+    mut rng: ResMut<FastRng>,
+    mut despawn_buffer: Local<Vec<Entity>>,
+    mut metrics: ResMut<FrameMetrics>,
+    time: Res<Time>,
+) {
+    if !state.initialized {
+        return;
+    }
+
+    let churn_count = ((state.target_count as f32) * state.churn_rate) as usize;
+    let churn_count = churn_count.max(10); // Minimum churn
+
+    despawn_buffer.clear();
+    despawn_buffer.extend(query.iter().take(churn_count));
+
+    metrics.record_structural_ops(despawn_buffer.len() + churn_count, time.delta_secs_f64());
+
+    for entity in despawn_buffer.drain(..) {
+        commands.entity(entity).despawn();
+    }
+
+    for _ in 0..churn_count {
+        commands
+            .spawn_empty()
+            .insert(BenchmarkEntity)
+            .insert(Position::random_with(&mut rng.0))
+            .insert(Velocity::random_with(&mut rng.0));
+    }
+}
+
+// =============================================================================
+// Added<T> Detection Workload
+// =============================================================================
+
+/// Scan for entities spawned since this system last ran.
+///
+/// Runs alongside `spawn_despawn_churn_system`, which keeps the entity count
+/// steady while spawning a fresh batch each frame. `Added<T>` still has to
+/// check the added tick of every matching entity, so the cost of the scan
+/// grows with the total entity count, not just the size of the new batch.
+pub fn added_detection_system(
+    query: Query<Entity, (With<BenchmarkEntity>, Added<BenchmarkEntity>)>,
+) {
+    let mut added: usize = 0;
+    for entity in &query {
+        black_box(entity);
+        added += 1;
+    }
+    black_box(added);
+}
+
 // =============================================================================
 // Component Add/Remove Workload
 // =============================================================================
@@ -194,6 +551,9 @@ pub fn component_add_remove_system(
     mut state: ResMut<ComponentToggleState>,
     with_toggle: Query<Entity, (With<BenchmarkEntity>, With<ToggleComponent>)>,
     without_toggle: Query<Entity, (With<BenchmarkEntity>, Without<ToggleComponent>)>,
+    mut metrics: ResMut<FrameMetrics>,
+    time: Res<Time>,
+    config: Res<BenchmarkConfig>,
 ) {
     if !state.initialized {
         return;
@@ -201,19 +561,27 @@ pub fn component_add_remove_system(
 
     state.frame_counter += 1;
 
-    // Toggle every N frames to make the benchmark measurable
-    // (every frame would be too aggressive and not representative)
-    if state.frame_counter % 10 != 0 {
+    // Toggle every `toggle_period` frames; longer periods model lighter,
+    // bursty churn and 1 migrates entities every frame
+    if state.frame_counter % config.toggle_period.max(1) != 0 {
         return;
     }
 
-    // Limit toggle count to avoid overwhelming the command queue
-    let toggle_count = with_toggle.iter().count().min(1000);
+    // Migrate `toggle_fraction` of each group, up to `toggle_max`, so the
+    // two archetypes stay roughly the same size
+    let fraction = config.toggle_fraction.clamp(0.0, 1.0) as f64;
+    let cap = config.toggle_max.unwrap_or(usize::MAX);
+    let share = |count: usize| ((count as f64 * fraction).round() as usize).min(cap);
+    let remove_count = share(with_toggle.iter().count());
+    let add_count = share(without_toggle.iter().count());
 
     // Collect entities first, then issue commands
     // This is slightly more efficient than interleaving iteration and commands
-    let to_remove: Vec<_> = with_toggle.iter().take(toggle_count).collect();
-    let to_add: Vec<_> = without_toggle.iter().take(toggle_count).collect();
+    let to_remove: Vec<_> = with_toggle.iter().take(remove_count).collect();
+    let to_add: Vec<_> = without_toggle.iter().take(add_count).collect();
+
+    // Rate of the toggle burst, held until the next toggle frame
+    metrics.record_structural_ops(to_remove.len() + to_add.len(), time.delta_secs_f64());
 
     // Remove ToggleComponent from entities that have it
     for entity in to_remove {
@@ -232,6 +600,292 @@ pub fn component_add_remove_system(
 // Batch Spawn Workload
 // =============================================================================
 
+/// Spawn `count` Position/Velocity entities as `spawn_batch` calls of at most `chunk_size`
+fn spawn_in_chunks(commands: &mut Commands, rng: &mut FastRng, count: usize, chunk_size: usize) {
+    let chunk_size = chunk_size.max(1);
+    let mut remaining = count;
+    while remaining > 0 {
+        let chunk = remaining.min(chunk_size);
+        let entities: Vec<_> = (0..chunk)
+            .map(|_| {
+                (
+                    BenchmarkEntity,
+                    Position::random_with(&mut rng.0),
+                    Velocity::random_with(&mut rng.0),
+                )
+            })
+            .collect();
+        commands.spawn_batch(entities);
+        remaining -= chunk;
+    }
+}
+
+/// Initial spawn for the chunked spawn workload
+pub fn spawn_chunked_setup(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut state: ResMut<SpawnDespawnState>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!(
+            "Setting up chunked spawn with {} entities in chunks of {}",
+            event.count, config.spawn_chunk_size
+        );
+
+        state.target_count = event.count;
+        state.initialized = true;
+
+        spawn_in_chunks(
+            &mut commands,
+            &mut rng,
+            event.count,
+            config.spawn_chunk_size,
+        );
+    }
+}
+
+/// Despawn every entity and respawn the full count in chunks, every frame.
+///
+/// Each `spawn_batch` call reserves entities and moves one Vec into a
+/// table; tiny chunks pay that fixed cost per entity, huge chunks pay for
+/// a large up-front allocation. Sweep `spawn_chunk_size` (C key) to find
+/// where bigger batches stop helping.
+pub fn chunked_spawn_system(
+    mut commands: Commands,
+    state: Res<SpawnDespawnState>,
+    query: Query<Entity, With<BenchmarkEntity>>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
+    mut metrics: ResMut<FrameMetrics>,
+    time: Res<Time>,
+) {
+    if !state.initialized {
+        return;
+    }
+
+    let mut despawned = 0;
+    for entity in &query {
+        commands.entity(entity).despawn();
+        despawned += 1;
+    }
+
+    metrics.record_structural_ops(despawned + state.target_count, time.delta_secs_f64());
+
+    spawn_in_chunks(
+        &mut commands,
+        &mut rng,
+        state.target_count,
+        config.spawn_chunk_size,
+    );
+}
+
+// =============================================================================
+// Parallel Prep Spawn Workload
+// =============================================================================
+
+/// Entities generated per data-prep task
+const PREP_CHUNK_SIZE: usize = 8192;
+
+type PrepBundle = (BenchmarkEntity, Position, Velocity);
+
+/// Build `count` bundles in `PREP_CHUNK_SIZE` chunks, each from its own seed.
+///
+/// Seeds are drawn up front so the parallel and sequential paths produce
+/// identical data and differ only in where the work runs.
+fn prepare_spawn_data(rng: &mut FastRng, count: usize, parallel: bool) -> Vec<PrepBundle> {
+    let chunks: Vec<(usize, u64)> = (0..count)
+        .step_by(PREP_CHUNK_SIZE)
+        .map(|start| (PREP_CHUNK_SIZE.min(count - start), rng.0.gen()))
+        .collect();
+
+    let build_chunk = |(len, seed): (usize, u64)| -> Vec<PrepBundle> {
+        let mut chunk_rng = Xoshiro256PlusPlus::seed_from_u64(seed);
+        (0..len)
+            .map(|_| {
+                (
+                    BenchmarkEntity,
+                    Position::random_with(&mut chunk_rng),
+                    Velocity::random_with(&mut chunk_rng),
+                )
+            })
+            .collect()
+    };
+
+    let prepared: Vec<Vec<PrepBundle>> = if parallel {
+        ComputeTaskPool::get().scope(|scope| {
+            for &chunk in &chunks {
+                scope.spawn(async move { build_chunk(chunk) });
+            }
+        })
+    } else {
+        chunks.into_iter().map(build_chunk).collect()
+    };
+
+    prepared.concat()
+}
+
+/// Initial spawn for the parallel prep spawn workload
+pub fn spawn_parallel_prep_setup(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut state: ResMut<SpawnDespawnState>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        info!(
+            "Setting up parallel prep spawn with {} entities ({} prep)",
+            event.count,
+            config
+                .variant_name(SelectedWorkload::ParallelPrepSpawn)
+                .unwrap_or_default()
+        );
+
+        state.target_count = event.count;
+        state.initialized = true;
+
+        commands.spawn_batch(prepare_spawn_data(
+            &mut rng,
+            event.count,
+            config.parallel_prep,
+        ));
+    }
+}
+
+/// Despawn everything, then respawn from bundle data prepared on the task pool.
+///
+/// `Commands` can't be shared across `par_iter` workers, so only the
+/// CPU-bound data generation is parallelized; the `spawn_batch` itself
+/// stays single-threaded. Toggle `parallel_prep` (P key) to compare
+/// against building the same data serially.
+pub fn parallel_prep_spawn_system(
+    mut commands: Commands,
+    state: Res<SpawnDespawnState>,
+    query: Query<Entity, With<BenchmarkEntity>>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
+    mut metrics: ResMut<FrameMetrics>,
+    time: Res<Time>,
+) {
+    if !state.initialized {
+        return;
+    }
+
+    let mut despawned = 0;
+    for entity in &query {
+        commands.entity(entity).despawn();
+        despawned += 1;
+    }
+
+    metrics.record_structural_ops(despawned + state.target_count, time.delta_secs_f64());
+
+    commands.spawn_batch(prepare_spawn_data(
+        &mut rng,
+        state.target_count,
+        config.parallel_prep,
+    ));
+}
+
+// =============================================================================
+// Recursive Despawn Workload
+// =============================================================================
+
+/// Spawn one tree of `depth` levels below the root, each node with
+/// `HIERARCHY_FANOUT` children. Returns the number of entities spawned.
+fn spawn_tree(commands: &mut Commands, depth: usize) -> usize {
+    let root = commands.spawn((BenchmarkEntity, Counter::default())).id();
+    let mut level = vec![root];
+    let mut spawned = 1;
+
+    for _ in 0..depth {
+        let mut next_level = Vec::with_capacity(level.len() * HIERARCHY_FANOUT);
+        for &parent in &level {
+            for _ in 0..HIERARCHY_FANOUT {
+                next_level.push(
+                    commands
+                        .spawn((BenchmarkEntity, Counter::default(), ChildOf(parent)))
+                        .id(),
+                );
+            }
+        }
+        spawned += next_level.len();
+        level = next_level;
+    }
+
+    spawned
+}
+
+/// Initial spawn for the recursive despawn workload.
+///
+/// The requested count is rounded down to whole trees (at least one);
+/// `SpawnDespawnState::target_count` holds the number of trees.
+pub fn spawn_hierarchy_setup(
+    mut commands: Commands,
+    mut spawn_events: MessageReader<SpawnEntitiesRequest>,
+    mut state: ResMut<SpawnDespawnState>,
+    config: Res<BenchmarkConfig>,
+) {
+    if let Some(event) = spawn_events.read().last() {
+        let trees = (event.count / config.hierarchy_tree_size()).max(1);
+        info!(
+            "Setting up recursive despawn with {} trees of depth {} ({} entities each)",
+            trees,
+            config.hierarchy_depth,
+            config.hierarchy_tree_size()
+        );
+
+        state.target_count = trees;
+        state.churn_rate = config.churn_rate;
+        state.initialized = true;
+
+        let mut spawned = 0;
+        for _ in 0..trees {
+            spawned += spawn_tree(&mut commands, config.hierarchy_depth);
+        }
+        state.current_count = spawned;
+    }
+}
+
+/// Despawn a fraction of the roots (taking their descendants with them)
+/// and spawn the same number of fresh trees.
+///
+/// `despawn` follows `Children` relationships, so each root despawn walks
+/// and removes its whole subtree. Compare results across depths (H key) to
+/// see how per-root cost grows with tree size.
+pub fn recursive_despawn_system(
+    mut commands: Commands,
+    state: Res<SpawnDespawnState>,
+    roots: Query<Entity, (With<BenchmarkEntity>, Without<ChildOf>)>,
+    config: Res<BenchmarkConfig>,
+    mut metrics: ResMut<FrameMetrics>,
+    time: Res<Time>,
+) {
+    if !state.initialized {
+        return;
+    }
+
+    let churn_trees = (((state.target_count as f32) * state.churn_rate) as usize).max(1);
+
+    let mut despawned = 0;
+    for root in roots.iter().take(churn_trees) {
+        commands.entity(root).despawn();
+        despawned += 1;
+    }
+
+    let mut spawned = 0;
+    for _ in 0..despawned {
+        spawned += spawn_tree(&mut commands, config.hierarchy_depth);
+    }
+
+    // Count every entity removed or created, not just the roots
+    metrics.record_structural_ops(
+        despawned * config.hierarchy_tree_size() + spawned,
+        time.delta_secs_f64(),
+    );
+}
+
 /// Test batch spawning performance specifically
 pub fn batch_spawn_system(
     _commands: Commands,
@@ -4,10 +4,13 @@
 //! cache-friendly patterns and efficient ECS design.
 
 use bevy::prelude::*;
-use rand::Rng;
-use rand::SeedableRng;
+use rand::{Rng, RngCore, SeedableRng};
+use rand_pcg::Pcg64;
 use rand_xoshiro::Xoshiro256PlusPlus;
 
+use crate::config::RngBackend;
+use crate::state::SelectedWorkload;
+
 // =============================================================================
 // Marker Components
 // =============================================================================
@@ -28,7 +31,9 @@ pub struct MarkedForDespawn;
 ///
 /// Using `thread_rng()` in each component's `random()` method creates a new
 /// RNG instance per call, which has significant overhead. This resource uses
-/// Xoshiro256++, a fast PRNG that's ~3x faster than ChaCha (thread_rng default).
+/// Xoshiro256++ by default, a fast PRNG that's ~3x faster than ChaCha
+/// (thread_rng default). `BenchmarkConfig::rng_backend` swaps in PCG or
+/// `thread_rng` to compare generator overhead.
 ///
 /// Benefits:
 /// - Single RNG instance reused across all spawn operations
@@ -42,24 +47,86 @@ pub struct MarkedForDespawn;
 /// }
 /// ```
 #[derive(Resource)]
-pub struct FastRng(pub Xoshiro256PlusPlus);
+pub struct FastRng(pub BenchRng);
 
 impl Default for FastRng {
     fn default() -> Self {
         // Seed from entropy for production, use fixed seed for reproducible benchmarks
-        Self(Xoshiro256PlusPlus::seed_from_u64(42))
+        Self::with_seed(crate::config::DEFAULT_RNG_SEED)
     }
 }
 
 impl FastRng {
     /// Create with a specific seed for reproducible benchmarks
     pub fn with_seed(seed: u64) -> Self {
-        Self(Xoshiro256PlusPlus::seed_from_u64(seed))
+        Self::with_backend(RngBackend::default(), seed)
+    }
+
+    /// Create a `backend` generator from `seed` (ignored by `ThreadRng`)
+    pub fn with_backend(backend: RngBackend, seed: u64) -> Self {
+        Self(match backend {
+            RngBackend::Xoshiro => BenchRng::Xoshiro(Xoshiro256PlusPlus::seed_from_u64(seed)),
+            RngBackend::Pcg => BenchRng::Pcg(Pcg64::seed_from_u64(seed)),
+            RngBackend::ThreadRng => BenchRng::ThreadRng,
+        })
+    }
+
+    /// Deterministic per-workload stream: `base_seed ^ workload index`.
+    ///
+    /// Gives each workload the same random data no matter which workloads
+    /// ran before it.
+    pub fn for_workload(base_seed: u64, backend: RngBackend, workload: SelectedWorkload) -> Self {
+        Self::with_backend(backend, base_seed ^ workload.index() as u64)
     }
 
     /// Create with random seed from system entropy
     pub fn from_entropy() -> Self {
-        Self(Xoshiro256PlusPlus::from_entropy())
+        Self(BenchRng::Xoshiro(Xoshiro256PlusPlus::from_entropy()))
+    }
+}
+
+/// The generator inside `FastRng`, one variant per `RngBackend`.
+///
+/// An enum rather than a boxed `RngCore` keeps draws statically dispatched,
+/// and lets `ThreadRng` (which isn't `Send`, so can't live in a resource)
+/// be fetched from its thread-local on every draw instead of stored.
+pub enum BenchRng {
+    Xoshiro(Xoshiro256PlusPlus),
+    Pcg(Pcg64),
+    ThreadRng,
+}
+
+impl RngCore for BenchRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Self::Xoshiro(rng) => rng.next_u32(),
+            Self::Pcg(rng) => rng.next_u32(),
+            Self::ThreadRng => rand::thread_rng().next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::Xoshiro(rng) => rng.next_u64(),
+            Self::Pcg(rng) => rng.next_u64(),
+            Self::ThreadRng => rand::thread_rng().next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Self::Xoshiro(rng) => rng.fill_bytes(dest),
+            Self::Pcg(rng) => rng.fill_bytes(dest),
+            Self::ThreadRng => rand::thread_rng().fill_bytes(dest),
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        match self {
+            Self::Xoshiro(rng) => rng.try_fill_bytes(dest),
+            Self::Pcg(rng) => rng.try_fill_bytes(dest),
+            Self::ThreadRng => rand::thread_rng().try_fill_bytes(dest),
+        }
     }
 }
 
@@ -100,6 +167,28 @@ impl Position {
     }
 }
 
+/// Sparse-set stored copy of `Position` for storage A/B runs.
+///
+/// Storage is a compile-time attribute, so comparing table and sparse-set
+/// storage on the same data needs a second component with identical fields.
+#[derive(Component, Default, Clone, Copy, Debug)]
+#[component(storage = "SparseSet")]
+pub struct PositionSparse {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl From<Position> for PositionSparse {
+    fn from(pos: Position) -> Self {
+        Self {
+            x: pos.x,
+            y: pos.y,
+            z: pos.z,
+        }
+    }
+}
+
 /// Velocity component for movement systems
 #[derive(Component, Default, Clone, Copy, Debug)]
 pub struct Velocity {
@@ -224,6 +313,61 @@ impl Default for HeavyPayload {
     }
 }
 
+/// Component with a runtime-sized payload, for sweeping data size per entity.
+///
+/// **Heap indirection caveat:** the floats live in a separate heap allocation
+/// per entity, not inline in the archetype table. The table only stores the
+/// `Box` pointer and length, so iteration chases one pointer per entity and
+/// the payloads are not contiguous in memory. Results therefore measure heap
+/// access patterns as much as ECS storage; compare against the inline
+/// `DataPayload` (16 floats) and `HeavyPayload` (64 floats) for the
+/// contiguous case.
+#[derive(Component, Clone)]
+pub struct VariablePayload {
+    pub values: Box<[f32]>,
+}
+
+impl VariablePayload {
+    /// Generate a random payload of `len` floats using provided RNG
+    #[inline]
+    pub fn random_with<R: Rng>(rng: &mut R, len: usize) -> Self {
+        Self {
+            values: (0..len).map(|_| rng.gen_range(-100.0..100.0)).collect(),
+        }
+    }
+}
+
+/// Number of heap-allocated floats owned by each `DropPayload`
+pub const DROP_PAYLOAD_LEN: usize = 64;
+
+/// Component owning heap data, so despawning it runs a real destructor.
+///
+/// `Position`/`Velocity` are `Copy` and need no drop glue; this component
+/// frees a `Vec` allocation on despawn, which is closer to components that
+/// hold strings, buffers or handles in real games.
+#[derive(Component, Clone, Default)]
+pub struct DropPayload {
+    pub values: Vec<f32>,
+}
+
+impl DropPayload {
+    /// Generate random payload using thread_rng (convenient but slower)
+    pub fn random() -> Self {
+        let mut rng = rand::thread_rng();
+        Self::random_with(&mut rng)
+    }
+
+    /// Generate a heap payload of `DROP_PAYLOAD_LEN` floats using provided RNG
+    #[inline]
+    pub fn random_with<R: Rng>(rng: &mut R) -> Self {
+        Self {
+            values: (0..DROP_PAYLOAD_LEN)
+                .map(|_| rng.gen_range(-100.0..100.0))
+                .collect(),
+        }
+    }
+}
+
 // =============================================================================
 // Archetype Fragmentation Components
 // =============================================================================
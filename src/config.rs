@@ -2,18 +2,115 @@
 //!
 //! Modify these values to adjust benchmark behavior.
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+
+use crate::state::SelectedWorkload;
+
 /// Target frame time in milliseconds (16.6ms = 60 FPS)
 pub const TARGET_FRAME_TIME_MS: f64 = 16.666;
 
 /// Alternative target for 30 FPS testing
 pub const TARGET_FRAME_TIME_30FPS_MS: f64 = 33.333;
 
+/// Alternative target for 120 FPS testing
+pub const TARGET_FRAME_TIME_120FPS_MS: f64 = 8.333;
+
+/// Alternative target for 144 FPS testing
+pub const TARGET_FRAME_TIME_144FPS_MS: f64 = 6.944;
+
+/// Targets cycled through at runtime with the T key
+pub const TARGET_FRAME_TIME_CYCLE_MS: [f64; 4] = [
+    TARGET_FRAME_TIME_30FPS_MS,
+    TARGET_FRAME_TIME_MS,
+    TARGET_FRAME_TIME_120FPS_MS,
+    TARGET_FRAME_TIME_144FPS_MS,
+];
+
 /// Number of warm-up frames to skip before measuring
 pub const WARMUP_FRAMES: usize = 60;
 
+/// Trailing warm-up frames the adaptive warm-up checks for stability
+pub const WARMUP_STABILITY_WINDOW: usize = 20;
+
+/// Coefficient of variation below which the adaptive warm-up ends
+pub const WARMUP_CV_THRESHOLD: f64 = 0.05;
+
+/// Adaptive warm-up gives up waiting for stability after this many frames
+pub const MAX_WARMUP_FRAMES: usize = 300;
+
+/// Warm-up frame on which the spawned entity count is validated
+/// (late enough for the spawn commands to have been applied)
+pub const SPAWN_VALIDATION_FRAME: usize = 2;
+
 /// Number of frames to sample for each measurement
 pub const SAMPLE_FRAMES: usize = 120;
 
+/// Sub-windows the sample window is split into for the median-of-medians
+pub const SAMPLE_SUB_WINDOWS: usize = 4;
+
+/// Extra process passes run at the end of warm-up to page in component data
+pub const CACHE_WARM_PASSES: usize = 3;
+
+/// Sampling ends early if the running median exceeds the target by this factor
+pub const EARLY_ABORT_MULTIPLIER: f64 = 5.0;
+
+/// Minimum samples collected before an early abort is considered
+pub const EARLY_ABORT_MIN_FRAMES: usize = 10;
+
+/// Default fraction of entities despawned and respawned per frame in churn workloads
+pub const DEFAULT_CHURN_RATE: f32 = 0.01;
+
+/// Default share of each group (with / without `ToggleComponent`) migrated
+/// per toggle in the component add/remove workload: all of it, up to
+/// `DEFAULT_TOGGLE_MAX`
+pub const DEFAULT_TOGGLE_FRACTION: f32 = 1.0;
+
+/// Default frames between toggles in the component add/remove workload
+pub const DEFAULT_TOGGLE_PERIOD: usize = 10;
+
+/// Default cap on entities migrated per group per toggle. Together with the
+/// defaults above this is the original fixed workload, so default results
+/// stay comparable with older reports.
+pub const DEFAULT_TOGGLE_MAX: usize = 1_000;
+
+/// Toggle fractions cycled with the U key
+pub const TOGGLE_FRACTIONS: [f32; 5] = [0.01, 0.02, 0.1, 0.5, 1.0];
+
+/// Frames between toggles cycled with the Y key
+pub const TOGGLE_PERIODS: [usize; 4] = [1, 5, 10, 30];
+
+/// Churn rate adjustment step for the [ and ] keys
+pub const CHURN_RATE_STEP: f32 = 0.005;
+
+/// Lower and upper bounds for the adjustable churn rate
+pub const MIN_CHURN_RATE: f32 = 0.001;
+pub const MAX_CHURN_RATE: f32 = 0.5;
+
+/// Chunk sizes cycled with the C key in the chunked spawn workload
+pub const SPAWN_CHUNK_SIZES: [usize; 5] = [1, 100, 1_000, 10_000, 100_000];
+
+/// Children per node in the recursive despawn workload's trees
+pub const HIERARCHY_FANOUT: usize = 4;
+
+/// In Run Condition Overhead, one guarded system in this many has a passing condition
+pub const RUN_CONDITION_PASS_EVERY: usize = 16;
+
+/// Counters each Many Systems / Run Condition Overhead system reads, so a
+/// system's body costs the same at any entity count
+pub const TRIVIAL_SYSTEM_READS: usize = 16;
+
+/// Tree depths (levels below the root) cycled with the H key
+pub const HIERARCHY_DEPTHS: [usize; 5] = [1, 2, 3, 4, 6];
+
+/// Widest query the Multi-Component Read workload can be set to (W key)
+pub const MAX_MULTI_COMPONENT_COUNT: usize = 6;
+
+/// Base seed for `FastRng`; the automated suite mixes in each workload's index
+pub const DEFAULT_RNG_SEED: u64 = 42;
+
 /// Initial entity count when starting a benchmark
 pub const INITIAL_ENTITY_COUNT: usize = 10_000;
 
@@ -23,6 +120,12 @@ pub const MIN_ENTITY_COUNT: usize = 100;
 /// Maximum entity count to test (effectively unlimited)
 pub const MAX_ENTITY_COUNT: usize = usize::MAX / 2;
 
+/// Entity cap for the quadratic pairwise combinations workload
+pub const PAIRWISE_MAX_ENTITY_COUNT: usize = 10_000;
+
+/// Where the pairwise combinations search starts (about 125k pairs per frame)
+pub const PAIRWISE_INITIAL_ENTITY_COUNT: usize = 500;
+
 /// Multiplier for exponential growth phase
 pub const GROWTH_MULTIPLIER: f64 = 2.0;
 
@@ -32,15 +135,885 @@ pub const MANUAL_STEP_SIZE: usize = 1_000;
 /// Large manual adjustment step (with shift held)
 pub const MANUAL_STEP_SIZE_LARGE: usize = 10_000;
 
+/// Factor the count is multiplied or divided by with ctrl held (log-scale steps)
+pub const MANUAL_STEP_FACTOR: f64 = 1.5;
+
 /// Minimum gap for binary search convergence (finer granularity)
 pub const MIN_CONVERGENCE_GAP: usize = 100;
 
-/// Frame history length for graph display
+/// Default frame history length for graph display
 pub const FRAME_HISTORY_LENGTH: usize = 300;
 
+/// Default number of bars in the frame time graph
+pub const GRAPH_BAR_COUNT: usize = 300;
+
+/// Frames to skip after startup before checking for vsync
+pub const VSYNC_DETECTION_SKIP_FRAMES: usize = 30;
+
+/// Frames sampled in the empty world to check for vsync
+pub const VSYNC_DETECTION_FRAMES: usize = 60;
+
+/// Common display refresh rates (Hz) that indicate a vsync-capped frame time
+pub const COMMON_REFRESH_RATES: [f64; 9] =
+    [30.0, 50.0, 60.0, 75.0, 90.0, 120.0, 144.0, 165.0, 240.0];
+
+/// Relative distance from a refresh interval that counts as "suspiciously close"
+pub const VSYNC_TOLERANCE: f64 = 0.02;
+
+/// Frame time percentiles reported by default (p95 and p99 are always included)
+pub const DEFAULT_PERCENTILES: [f64; 5] = [50.0, 90.0, 95.0, 99.0, 99.9];
+
+/// Calibration schedule runs per thermal drift measurement (median is used)
+pub const DRIFT_BASELINE_SAMPLES: usize = 31;
+
+/// Loop iterations in the drift calibration system
+pub const DRIFT_CALIBRATION_ITERATIONS: u64 = 200_000;
+
+/// Coefficient of variation above which a result is flagged as noisy
+pub const STABILITY_CV_THRESHOLD: f64 = 0.1;
+
+/// Times the automated suite re-runs a workload whose result came out noisy
+pub const DEFAULT_MAX_RETRIES: usize = 2;
+
+/// Idle frames the automated suite waits between one workload's teardown
+/// and the next workload's warm-up
+pub const COOLDOWN_FRAMES: usize = 10;
+
 /// Results output directory
 pub const RESULTS_DIR: &str = "benchmark_results";
 
+/// Append-only phase transition log written with `--trace-log`
+pub const TRACE_LOG_FILE: &str = "phase_trace.jsonl";
+
+/// Environment variable overriding `RESULTS_DIR`
+pub const RESULTS_DIR_ENV: &str = "BENCH_RESULTS_DIR";
+
+/// Resolve the output directory: `BENCH_RESULTS_DIR` if set and non-empty,
+/// otherwise `RESULTS_DIR` relative to the working directory
+pub fn results_dir() -> PathBuf {
+    std::env::var_os(RESULTS_DIR_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(RESULTS_DIR))
+}
+
+/// Runtime benchmark configuration.
+///
+/// Insert a customized instance before adding `BenchmarkPlugin` to override
+/// the defaults; the plugin only initializes it if it is missing.
+#[derive(Resource, Debug, Clone)]
+pub struct BenchmarkConfig {
+    /// Active target frame time (ms) for workloads without an override
+    pub default_target_ms: f64,
+    /// Per-workload target frame time overrides (ms).
+    /// Workloads without an entry use `default_target_ms`.
+    pub workload_targets: HashMap<SelectedWorkload, f64>,
+    /// Number of times the automated suite is repeated before aggregating
+    pub repeats: usize,
+    /// The automated suite re-runs a workload whose stability CV is above this
+    pub retry_cv_threshold: f64,
+    /// Re-runs allowed per workload for noisy results (0 disables retrying)
+    pub max_retries: usize,
+    /// Idle frames between workloads in the automated suite (0 disables the cooldown)
+    pub cooldown_frames: usize,
+    /// Clock used for frame time samples
+    pub timing: TimingSource,
+    /// Frame time percentiles to compute (e.g. 99.9 for p99.9)
+    pub percentiles: Vec<f64>,
+    /// Sub-windows per sample window; the search decides on the median of
+    /// their medians (1 uses the plain median)
+    pub sample_sub_windows: usize,
+    /// Frames timed for each measurement
+    pub sample_frames: usize,
+    /// Frames added to the end of warm-up so the first sampled frames don't
+    /// touch cold component memory (iteration workloads only; 0 disables)
+    pub cache_warm_passes: usize,
+    /// Number of f32 values per entity in the variable payload workload
+    pub variable_payload_len: usize,
+    /// Rise in the empty-world baseline (percent) that flags thermal drift
+    pub thermal_drift_percent: f64,
+    /// Fraction of entities churned per frame in spawn/despawn style workloads
+    pub churn_rate: f32,
+    /// Share of each group the component add/remove workload migrates per
+    /// toggle (0..=1): light vs heavy structural churn
+    pub toggle_fraction: f32,
+    /// Frames between toggles in the component add/remove workload (1 = every frame)
+    pub toggle_period: usize,
+    /// Most entities per group the component add/remove workload migrates
+    /// per toggle (None: uncapped, `toggle_fraction` alone decides)
+    pub toggle_max: Option<usize>,
+    /// Show the frame graph as an exponential moving average instead of raw frames
+    pub graph_smoothing_enabled: bool,
+    /// EMA weight of the newest frame (0..=1, 1.0 = no smoothing)
+    pub graph_smoothing_factor: f64,
+    /// Number of trivial systems registered for the many systems and run
+    /// condition overhead workloads. Read once when the plugin is built.
+    pub many_systems_count: usize,
+    /// Workloads run by the automated suite, in order
+    pub suite_workloads: Vec<SelectedWorkload>,
+    /// How the fragmented workload splits entities across archetype patterns
+    pub fragment_distribution: FragmentDistribution,
+    /// Entities per `spawn_batch` call in the chunked spawn workload
+    pub spawn_chunk_size: usize,
+    /// Base seed the automated suite re-seeds `FastRng` from for each workload
+    pub rng_seed: u64,
+    /// Generator behind `FastRng`
+    pub rng_backend: RngBackend,
+    /// Bitflag Branching sub-variant: branch on `EntityVariant` flags (true) or not
+    pub bitflag_checks: bool,
+    /// Save the report on exit if it holds any results, so an interrupted run isn't lost
+    pub autosave_on_exit: bool,
+    /// Parallel Prep Spawn sub-variant: build bundles on the task pool (true) or serially
+    pub parallel_prep: bool,
+    /// Levels below the root in the recursive despawn workload's trees
+    pub hierarchy_depth: usize,
+    /// End warm-up once frame times settle (CV over `WARMUP_STABILITY_WINDOW`
+    /// frames below `WARMUP_CV_THRESHOLD`) instead of after `WARMUP_FRAMES`.
+    /// Off by default so results stay comparable with older reports.
+    pub adaptive_warmup: bool,
+    /// Saved reports to compare in the Results screen instead of benchmarking
+    pub compare_reports: Option<(PathBuf, PathBuf)>,
+    /// Local vs Res sub-variant: cache the derived value in `Local<T>` (true) or not
+    pub local_cache: bool,
+    /// Frames kept in the rolling history. Read once when the plugin is built.
+    pub frame_history_length: usize,
+    /// Bars drawn in the frame graph; the history is resampled to fit.
+    /// Read once when the UI is set up.
+    pub graph_bar_count: usize,
+    /// Append every benchmark phase transition to `TRACE_LOG_FILE` (`--trace-log`)
+    pub trace_log: bool,
+    /// Position Iteration sub-variant: sparse-set (`PositionSparse`) instead of
+    /// table (`Position`) storage. Read when entities are spawned.
+    pub sparse_storage: bool,
+    /// Hold every automatic phase transition until `N` is pressed (`--step`)
+    pub step_mode: bool,
+    /// Bulk Despawn sub-variant: `World::clear_entities` (true) or per-entity despawns
+    pub bulk_despawn: bool,
+    /// Serve live metrics in Prometheus text format on this port (`--metrics-port`).
+    /// Read once when the plugin is built.
+    pub metrics_port: Option<u16>,
+    /// Bind the metrics endpoint on every interface instead of only
+    /// localhost (`--metrics-bind-all`), for scraping from another machine
+    pub metrics_bind_all: bool,
+    /// Disjoint Parallel sub-variant: chain the two systems instead of
+    /// leaving them free to run in parallel
+    pub disjoint_chained: bool,
+    /// Entity count every search starts from (`--initial-count N`); None keeps
+    /// the count restored from the last session
+    pub initial_entity_count: Option<usize>,
+    /// Per-workload starting counts (`--initial-count 2=500000`), taking
+    /// precedence over `initial_entity_count`
+    pub initial_counts: HashMap<SelectedWorkload, usize>,
+    /// Iter Style sub-variant: `iter().for_each` (true) or a `for` loop
+    pub iter_for_each: bool,
+    /// Wrap per-entity reads in the core iteration workloads in `black_box`
+    /// (false: plain accumulation into `IterationSink`, `--no-black-box`)
+    pub black_box_reads: bool,
+    /// Entity Map Lookup sub-variant: read `Counter` directly (true) instead of
+    /// looking each entity up in the `EntityHashMap` side table
+    pub entity_map_direct: bool,
+    /// Components read per entity by Multi-Component Read, 1 to
+    /// `MAX_MULTI_COMPONENT_COUNT`; every entity carries all of them
+    pub multi_component_count: usize,
+    /// Run Condition Overhead sub-variant: every guarded system's condition
+    /// passes (true) instead of one in `RUN_CONDITION_PASS_EVERY`
+    pub run_conditions_pass: bool,
+}
+
+/// Where frame time samples come from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimingSource {
+    /// Bevy's `Time::delta` (whole frame, including rendering and UI)
+    #[default]
+    FrameDelta,
+    /// `Instant` span around the benchmark system sets only
+    WorkloadSpan,
+}
+
+/// Generator behind `FastRng`, for comparing PRNG overhead in spawn-heavy workloads
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RngBackend {
+    /// Xoshiro256++ (`rand_xoshiro`)
+    #[default]
+    Xoshiro,
+    /// PCG XSL RR 128/64 (`rand_pcg::Pcg64`)
+    Pcg,
+    /// `rand::thread_rng()` fetched for every draw; seeded from entropy, so
+    /// runs are not reproducible
+    ThreadRng,
+}
+
+impl RngBackend {
+    /// Name recorded in results and accepted by `--rng`
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Xoshiro => "xoshiro",
+            Self::Pcg => "pcg",
+            Self::ThreadRng => "thread_rng",
+        }
+    }
+
+    /// Parse a `--rng` value
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::Xoshiro, Self::Pcg, Self::ThreadRng]
+            .into_iter()
+            .find(|backend| backend.name().eq_ignore_ascii_case(value.trim()))
+    }
+}
+
+/// Share of entities per archetype pattern in the fragmented workload
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum FragmentDistribution {
+    /// Same number of entities in every pattern
+    #[default]
+    Uniform,
+    /// Relative weight per pattern, in pattern order (missing entries weigh 0)
+    Weighted(Vec<f32>),
+}
+
+impl FragmentDistribution {
+    /// `dominant` share in the first pattern, the rest spread evenly over the others
+    pub fn skewed(dominant: f32, patterns: usize) -> Self {
+        let dominant = dominant.clamp(0.0, 1.0);
+        let rest = (1.0 - dominant) / patterns.saturating_sub(1).max(1) as f32;
+        let mut weights = vec![rest; patterns];
+        if let Some(first) = weights.first_mut() {
+            *first = dominant;
+        }
+        Self::Weighted(weights)
+    }
+
+    /// Split `count` entities into per-pattern counts that sum to exactly `count`
+    pub fn split(&self, count: usize, patterns: usize) -> Vec<usize> {
+        if patterns == 0 {
+            return Vec::new();
+        }
+
+        let weights: Vec<f32> = match self {
+            Self::Uniform => vec![1.0; patterns],
+            Self::Weighted(weights) => (0..patterns)
+                .map(|i| weights.get(i).copied().unwrap_or(0.0).max(0.0))
+                .collect(),
+        };
+        // Summed in f64 like the shares, so tiny weights aren't lost from the
+        // total and the shares can't add up to more than `count`
+        let total: f64 = weights.iter().map(|&w| w as f64).sum();
+        if total <= 0.0 {
+            return Self::Uniform.split(count, patterns);
+        }
+
+        let mut counts: Vec<usize> = weights
+            .iter()
+            .map(|&w| ((count as f64) * (w as f64) / total) as usize)
+            .collect();
+
+        // Hand the rounding remainder out one by one, heaviest patterns first
+        let mut order: Vec<usize> = (0..patterns).collect();
+        order.sort_by(|&a, &b| weights[b].total_cmp(&weights[a]));
+        let assigned: usize = counts.iter().sum();
+        for i in 0..count.saturating_sub(assigned) {
+            counts[order[i % patterns]] += 1;
+        }
+        counts
+    }
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        Self {
+            default_target_ms: TARGET_FRAME_TIME_MS,
+            workload_targets: HashMap::new(),
+            repeats: 1,
+            retry_cv_threshold: STABILITY_CV_THRESHOLD,
+            max_retries: DEFAULT_MAX_RETRIES,
+            cooldown_frames: COOLDOWN_FRAMES,
+            timing: TimingSource::default(),
+            percentiles: DEFAULT_PERCENTILES.to_vec(),
+            sample_sub_windows: SAMPLE_SUB_WINDOWS,
+            sample_frames: SAMPLE_FRAMES,
+            cache_warm_passes: CACHE_WARM_PASSES,
+            variable_payload_len: 16,
+            thermal_drift_percent: 10.0,
+            churn_rate: DEFAULT_CHURN_RATE,
+            toggle_fraction: DEFAULT_TOGGLE_FRACTION,
+            toggle_period: DEFAULT_TOGGLE_PERIOD,
+            toggle_max: Some(DEFAULT_TOGGLE_MAX),
+            graph_smoothing_enabled: false,
+            graph_smoothing_factor: 0.2,
+            many_systems_count: 256,
+            suite_workloads: SelectedWorkload::suite_default(),
+            fragment_distribution: FragmentDistribution::default(),
+            spawn_chunk_size: 100,
+            rng_seed: DEFAULT_RNG_SEED,
+            rng_backend: RngBackend::default(),
+            bitflag_checks: true,
+            autosave_on_exit: true,
+            parallel_prep: true,
+            hierarchy_depth: 3,
+            adaptive_warmup: false,
+            compare_reports: None,
+            local_cache: true,
+            frame_history_length: FRAME_HISTORY_LENGTH,
+            graph_bar_count: GRAPH_BAR_COUNT,
+            trace_log: false,
+            sparse_storage: false,
+            step_mode: false,
+            bulk_despawn: true,
+            metrics_port: None,
+            metrics_bind_all: false,
+            disjoint_chained: false,
+            initial_entity_count: None,
+            initial_counts: HashMap::new(),
+            iter_for_each: false,
+            black_box_reads: true,
+            entity_map_direct: false,
+            multi_component_count: 3,
+            run_conditions_pass: false,
+        }
+    }
+}
+
+impl BenchmarkConfig {
+    /// Label of the sub-variant `workload` runs in, for workloads that have them
+    pub fn variant_name(&self, workload: SelectedWorkload) -> Option<String> {
+        match workload {
+            SelectedWorkload::BitflagBranching => Some(
+                if self.bitflag_checks {
+                    "checks"
+                } else {
+                    "no checks"
+                }
+                .to_string(),
+            ),
+            SelectedWorkload::ParallelPrepSpawn => Some(
+                if self.parallel_prep {
+                    "parallel"
+                } else {
+                    "sequential"
+                }
+                .to_string(),
+            ),
+            SelectedWorkload::RecursiveDespawn => Some(format!("depth {}", self.hierarchy_depth)),
+            SelectedWorkload::LocalVsRes => {
+                Some(if self.local_cache { "Local" } else { "Res" }.to_string())
+            }
+            SelectedWorkload::BulkDespawn => Some(
+                if self.bulk_despawn {
+                    "bulk"
+                } else {
+                    "per-entity"
+                }
+                .to_string(),
+            ),
+            SelectedWorkload::DisjointParallel => Some(
+                if self.disjoint_chained {
+                    "chained"
+                } else {
+                    "parallel"
+                }
+                .to_string(),
+            ),
+            SelectedWorkload::IterStyle => Some(
+                if self.iter_for_each {
+                    "for_each"
+                } else {
+                    "for loop"
+                }
+                .to_string(),
+            ),
+            SelectedWorkload::MultiComponentRead => {
+                Some(format!("{} components", self.multi_component_count))
+            }
+            SelectedWorkload::EntityMapLookup => Some(
+                if self.entity_map_direct {
+                    "component"
+                } else {
+                    "EntityHashMap"
+                }
+                .to_string(),
+            ),
+            SelectedWorkload::RunConditionOverhead => Some(
+                if self.run_conditions_pass {
+                    "all true"
+                } else {
+                    "mostly false"
+                }
+                .to_string(),
+            ),
+            SelectedWorkload::PositionIteration => Some(
+                if self.sparse_storage {
+                    "sparse set"
+                } else {
+                    "table"
+                }
+                .to_string(),
+            ),
+            // The defaults keep no label so they group with older reports
+            SelectedWorkload::ComponentAddRemove
+                if self.toggle_fraction != DEFAULT_TOGGLE_FRACTION
+                    || self.toggle_period != DEFAULT_TOGGLE_PERIOD
+                    || self.toggle_max != Some(DEFAULT_TOGGLE_MAX) =>
+            {
+                Some(format!(
+                    "{}% / {}f / cap {}",
+                    (self.toggle_fraction * 1000.0).round() / 10.0,
+                    self.toggle_period,
+                    self.toggle_max
+                        .map_or_else(|| "none".to_string(), |max| max.to_string())
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// Guarded systems in Run Condition Overhead whose condition passes
+    pub fn run_condition_passing_systems(&self) -> usize {
+        if self.run_conditions_pass {
+            self.many_systems_count
+        } else {
+            self.many_systems_count.div_ceil(RUN_CONDITION_PASS_EVERY)
+        }
+    }
+
+    /// Entities in one recursive despawn tree: the root plus every level below it
+    pub fn hierarchy_tree_size(&self) -> usize {
+        (0..=self.hierarchy_depth)
+            .map(|level| HIERARCHY_FANOUT.pow(level as u32))
+            .sum()
+    }
+
+    /// Clamp every numeric setting to a range the benchmark can run with,
+    /// logging each value that had to change.
+    ///
+    /// Zero sample frames or sub-windows, a non-positive target or a NaN
+    /// rate would otherwise divide by zero or stall the search. Called when
+    /// `BenchmarkRunnerPlugin` is built; call it again after changing the
+    /// config at runtime.
+    pub fn validate(&mut self) {
+        let defaults = Self::default();
+        let mut changes = Vec::new();
+        macro_rules! clamp {
+            ($field:ident, $value:expr) => {{
+                let value = $value;
+                if value != self.$field {
+                    changes.push(format!(
+                        "{}: {:?} -> {:?}",
+                        stringify!($field),
+                        self.$field,
+                        value
+                    ));
+                    self.$field = value;
+                }
+            }};
+        }
+        // NaN and infinities fall back to the default rather than clamping
+        let positive = |value: f64, fallback: f64| {
+            if value.is_finite() && value > 0.0 {
+                value
+            } else {
+                fallback
+            }
+        };
+        let unit = |value: f32, fallback: f32| {
+            if value.is_finite() {
+                value.clamp(0.0, 1.0)
+            } else {
+                fallback
+            }
+        };
+
+        clamp!(
+            default_target_ms,
+            positive(self.default_target_ms, TARGET_FRAME_TIME_MS)
+        );
+        for (workload, target) in self.workload_targets.iter_mut() {
+            let valid = positive(*target, self.default_target_ms);
+            if valid != *target {
+                changes.push(format!(
+                    "{} target: {:?} -> {:?}",
+                    workload.name(),
+                    target,
+                    valid
+                ));
+                *target = valid;
+            }
+        }
+        clamp!(repeats, self.repeats.max(1));
+        clamp!(
+            retry_cv_threshold,
+            if self.retry_cv_threshold >= 0.0 && self.retry_cv_threshold.is_finite() {
+                self.retry_cv_threshold
+            } else {
+                defaults.retry_cv_threshold
+            }
+        );
+        // Keep the first of any duplicates, and the defaults if nothing is left
+        let mut percentiles = Vec::new();
+        for p in self.percentiles.iter().copied() {
+            if p.is_finite() && p > 0.0 && p <= 100.0 && !percentiles.contains(&p) {
+                percentiles.push(p);
+            }
+        }
+        if percentiles.is_empty() {
+            percentiles = DEFAULT_PERCENTILES.to_vec();
+        }
+        clamp!(percentiles, percentiles);
+        clamp!(sample_sub_windows, self.sample_sub_windows.max(1));
+        clamp!(sample_frames, self.sample_frames.max(1));
+        clamp!(
+            thermal_drift_percent,
+            positive(self.thermal_drift_percent, defaults.thermal_drift_percent)
+        );
+        clamp!(
+            churn_rate,
+            if self.churn_rate.is_finite() {
+                self.churn_rate.clamp(MIN_CHURN_RATE, MAX_CHURN_RATE)
+            } else {
+                defaults.churn_rate
+            }
+        );
+        clamp!(
+            toggle_fraction,
+            unit(self.toggle_fraction, defaults.toggle_fraction)
+        );
+        clamp!(toggle_period, self.toggle_period.max(1));
+        clamp!(toggle_max, self.toggle_max.map(|max| max.max(1)));
+        clamp!(
+            graph_smoothing_factor,
+            if self.graph_smoothing_factor.is_finite() {
+                self.graph_smoothing_factor.clamp(0.0, 1.0)
+            } else {
+                defaults.graph_smoothing_factor
+            }
+        );
+        clamp!(spawn_chunk_size, self.spawn_chunk_size.max(1));
+        // Deeper trees overflow `hierarchy_tree_size`
+        let max_depth = HIERARCHY_DEPTHS.iter().copied().max().unwrap_or(1);
+        clamp!(hierarchy_depth, self.hierarchy_depth.min(max_depth));
+        clamp!(frame_history_length, self.frame_history_length.max(1));
+        clamp!(graph_bar_count, self.graph_bar_count.max(1));
+        clamp!(
+            multi_component_count,
+            self.multi_component_count
+                .clamp(1, MAX_MULTI_COMPONENT_COUNT)
+        );
+
+        for change in changes {
+            warn!("Config adjusted: {}", change);
+        }
+    }
+
+    /// Restore every setting to its default and describe what changed.
+    ///
+    /// Settings read once at startup (`many_systems_count`, the history
+    /// lengths, the metrics endpoint) are kept, since changing them at runtime has
+    /// no effect. So are the command line choices, which the user made for
+    /// this whole session.
+    pub fn reset_to_default(&mut self) -> Vec<String> {
+        let kept = Self {
+            many_systems_count: self.many_systems_count,
+            frame_history_length: self.frame_history_length,
+            graph_bar_count: self.graph_bar_count,
+            metrics_port: self.metrics_port,
+            metrics_bind_all: self.metrics_bind_all,
+            suite_workloads: self.suite_workloads.clone(),
+            rng_backend: self.rng_backend,
+            compare_reports: self.compare_reports.clone(),
+            trace_log: self.trace_log,
+            step_mode: self.step_mode,
+            initial_entity_count: self.initial_entity_count,
+            initial_counts: self.initial_counts.clone(),
+            black_box_reads: self.black_box_reads,
+            toggle_fraction: self.toggle_fraction,
+            toggle_period: self.toggle_period,
+            toggle_max: self.toggle_max,
+            ..Self::default()
+        };
+        let old = std::mem::replace(self, kept);
+
+        let mut changes = Vec::new();
+        macro_rules! diff {
+            ($($field:ident),* $(,)?) => {
+                $(
+                    let (before, after) = (format!("{:?}", old.$field), format!("{:?}", self.$field));
+                    if before != after {
+                        changes.push(format!("{}: {} -> {}", stringify!($field), before, after));
+                    }
+                )*
+            };
+        }
+        diff!(
+            default_target_ms,
+            workload_targets,
+            repeats,
+            retry_cv_threshold,
+            max_retries,
+            cooldown_frames,
+            timing,
+            percentiles,
+            sample_sub_windows,
+            sample_frames,
+            cache_warm_passes,
+            variable_payload_len,
+            thermal_drift_percent,
+            churn_rate,
+            graph_smoothing_enabled,
+            graph_smoothing_factor,
+            fragment_distribution,
+            spawn_chunk_size,
+            rng_seed,
+            bitflag_checks,
+            autosave_on_exit,
+            parallel_prep,
+            hierarchy_depth,
+            adaptive_warmup,
+            local_cache,
+            sparse_storage,
+            bulk_despawn,
+            disjoint_chained,
+            iter_for_each,
+            entity_map_direct,
+            multi_component_count,
+            run_conditions_pass,
+        );
+        changes
+    }
+
+    /// Apply command line flags (`--workloads 2,5`, `--compare a.json b.json`, `--trace-log`,
+    /// `--step`, `--metrics-port 9100`, `--metrics-bind-all`, `--initial-count 50000` /
+    /// `--initial-count 2=500000`, `--no-black-box`, `--rng pcg`, `--toggle-fraction 0.1`,
+    /// `--toggle-period 1`, `--toggle-max none`)
+    pub fn with_args(mut self, args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--trace-log" {
+                self.trace_log = true;
+                continue;
+            }
+            if arg == "--step" {
+                self.step_mode = true;
+                continue;
+            }
+            if arg == "--metrics-bind-all" {
+                self.metrics_bind_all = true;
+                continue;
+            }
+            if arg == "--no-black-box" {
+                self.black_box_reads = false;
+                continue;
+            }
+            if arg == "--initial-count" {
+                let value = args.next().ok_or(
+                    "--initial-count needs a count, e.g. --initial-count 50000 or 2=500000",
+                )?;
+                self.parse_initial_count(&value)?;
+                continue;
+            }
+            if arg == "--rng" {
+                let value = args
+                    .next()
+                    .ok_or("--rng needs a generator: xoshiro, pcg or thread_rng")?;
+                self.rng_backend = RngBackend::parse(&value).ok_or_else(|| {
+                    format!(
+                        "Unknown --rng value: {} (xoshiro, pcg or thread_rng)",
+                        value
+                    )
+                })?;
+                continue;
+            }
+            if arg == "--toggle-fraction" {
+                let value = args
+                    .next()
+                    .ok_or("--toggle-fraction needs a share, e.g. --toggle-fraction 0.1")?;
+                self.toggle_fraction = value
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|fraction| (0.0..=1.0).contains(fraction))
+                    .ok_or_else(|| {
+                        format!("Invalid --toggle-fraction value: {} (0 to 1)", value)
+                    })?;
+                continue;
+            }
+            if arg == "--toggle-period" {
+                let value = args
+                    .next()
+                    .ok_or("--toggle-period needs a frame count, e.g. --toggle-period 1")?;
+                self.toggle_period = value
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|&period| period > 0)
+                    .ok_or_else(|| format!("Invalid --toggle-period value: {}", value))?;
+                continue;
+            }
+            if arg == "--toggle-max" {
+                let value = args
+                    .next()
+                    .ok_or("--toggle-max needs a count or none, e.g. --toggle-max 5000")?;
+                self.toggle_max = if value == "none" {
+                    None
+                } else {
+                    Some(
+                        value
+                            .parse::<usize>()
+                            .ok()
+                            .filter(|&max| max > 0)
+                            .ok_or_else(|| format!("Invalid --toggle-max value: {}", value))?,
+                    )
+                };
+                continue;
+            }
+            if arg == "--metrics-port" {
+                let port = args
+                    .next()
+                    .ok_or("--metrics-port needs a port, e.g. --metrics-port 9100")?;
+                let port = port
+                    .parse()
+                    .map_err(|_| format!("Invalid --metrics-port value: {}", port))?;
+                self.metrics_port = Some(port);
+                continue;
+            }
+            if arg == "--compare" {
+                let (Some(a), Some(b)) = (args.next(), args.next()) else {
+                    return Err(
+                        "--compare needs two report paths, e.g. --compare a.json b.json"
+                            .to_string(),
+                    );
+                };
+                self.compare_reports = Some((PathBuf::from(a), PathBuf::from(b)));
+                continue;
+            }
+
+            let value = match arg.split_once('=') {
+                Some((flag, value)) if flag == "--workloads" => Some(value.to_string()),
+                _ if arg == "--workloads" => args.next(),
+                _ => return Err(format!("Unknown argument: {}", arg)),
+            };
+            let value = value.ok_or("--workloads needs a value, e.g. --workloads 2,5")?;
+            self.suite_workloads = SelectedWorkload::parse_list(&value)?;
+        }
+        Ok(self)
+    }
+
+    /// Apply one `--initial-count` value: `N` for every workload, or
+    /// `WORKLOAD=N` (key hint or name) for one
+    fn parse_initial_count(&mut self, value: &str) -> Result<(), String> {
+        let parse_count = |count: &str| {
+            count
+                .trim()
+                .parse::<usize>()
+                .map_err(|_| format!("Invalid --initial-count value: {}", value))
+        };
+
+        match value.split_once('=') {
+            Some((workload, count)) => {
+                let [workload] = SelectedWorkload::parse_list(workload)?[..] else {
+                    return Err(format!("--initial-count takes one workload: {}", value));
+                };
+                self.initial_counts.insert(workload, parse_count(count)?);
+            }
+            None => self.initial_entity_count = Some(parse_count(value)?),
+        }
+        Ok(())
+    }
+
+    /// Set the starting entity count for a specific workload
+    pub fn with_initial_count(mut self, workload: SelectedWorkload, count: usize) -> Self {
+        self.initial_counts.insert(workload, count);
+        self
+    }
+
+    /// Configured starting entity count for a workload (None when not set)
+    pub fn initial_count(&self, workload: SelectedWorkload) -> Option<usize> {
+        self.initial_counts
+            .get(&workload)
+            .copied()
+            .or(self.initial_entity_count)
+    }
+
+    /// Set the target frame time for a specific workload
+    pub fn with_target(mut self, workload: SelectedWorkload, target_ms: f64) -> Self {
+        self.workload_targets.insert(workload, target_ms);
+        self
+    }
+
+    /// Get the target frame time for a workload (falls back to the default target)
+    pub fn target_frame_time_ms(&self, workload: SelectedWorkload) -> f64 {
+        self.workload_targets
+            .get(&workload)
+            .copied()
+            .unwrap_or(self.default_target_ms)
+    }
+
+    /// Step the churn rate up or down, clamped to `MIN_CHURN_RATE..=MAX_CHURN_RATE`
+    pub fn adjust_churn_rate(&mut self, increase: bool) {
+        let step = if increase {
+            CHURN_RATE_STEP
+        } else {
+            -CHURN_RATE_STEP
+        };
+        self.churn_rate = (self.churn_rate + step).clamp(MIN_CHURN_RATE, MAX_CHURN_RATE);
+    }
+
+    /// Advance the chunk size to the next entry in `SPAWN_CHUNK_SIZES`
+    pub fn cycle_spawn_chunk_size(&mut self) {
+        let next = SPAWN_CHUNK_SIZES
+            .iter()
+            .position(|&size| size == self.spawn_chunk_size)
+            .map_or(0, |i| (i + 1) % SPAWN_CHUNK_SIZES.len());
+        self.spawn_chunk_size = SPAWN_CHUNK_SIZES[next];
+    }
+
+    /// Advance the toggle fraction to the next entry in `TOGGLE_FRACTIONS`
+    pub fn cycle_toggle_fraction(&mut self) {
+        let next = TOGGLE_FRACTIONS
+            .iter()
+            .position(|&fraction| fraction == self.toggle_fraction)
+            .map_or(0, |i| (i + 1) % TOGGLE_FRACTIONS.len());
+        self.toggle_fraction = TOGGLE_FRACTIONS[next];
+    }
+
+    /// Advance the toggle period to the next entry in `TOGGLE_PERIODS`
+    pub fn cycle_toggle_period(&mut self) {
+        let next = TOGGLE_PERIODS
+            .iter()
+            .position(|&period| period == self.toggle_period)
+            .map_or(0, |i| (i + 1) % TOGGLE_PERIODS.len());
+        self.toggle_period = TOGGLE_PERIODS[next];
+    }
+
+    /// Switch the per-toggle cap between `DEFAULT_TOGGLE_MAX` and uncapped
+    pub fn toggle_toggle_max(&mut self) {
+        self.toggle_max = match self.toggle_max {
+            Some(_) => None,
+            None => Some(DEFAULT_TOGGLE_MAX),
+        };
+    }
+
+    /// Advance the tree depth to the next entry in `HIERARCHY_DEPTHS`
+    pub fn cycle_hierarchy_depth(&mut self) {
+        let next = HIERARCHY_DEPTHS
+            .iter()
+            .position(|&depth| depth == self.hierarchy_depth)
+            .map_or(0, |i| (i + 1) % HIERARCHY_DEPTHS.len());
+        self.hierarchy_depth = HIERARCHY_DEPTHS[next];
+    }
+
+    /// Read one more component in Multi-Component Read, wrapping from
+    /// `MAX_MULTI_COMPONENT_COUNT` back to 1
+    pub fn cycle_multi_component_count(&mut self) {
+        self.multi_component_count = self.multi_component_count % MAX_MULTI_COMPONENT_COUNT + 1;
+    }
+
+    /// Advance the default target to the next entry in `TARGET_FRAME_TIME_CYCLE_MS`
+    pub fn cycle_default_target(&mut self) {
+        let current = TARGET_FRAME_TIME_CYCLE_MS
+            .iter()
+            .position(|&t| (t - self.default_target_ms).abs() < 0.01);
+        let next = current.map_or(0, |i| (i + 1) % TARGET_FRAME_TIME_CYCLE_MS.len());
+        self.default_target_ms = TARGET_FRAME_TIME_CYCLE_MS[next];
+    }
+}
+
 /// UI Colors
 pub mod colors {
     use bevy::prelude::*;
@@ -56,6 +1029,7 @@ pub mod colors {
     pub const GRAPH_LINE: Color = Color::srgb(0.4, 0.8, 0.95);
     pub const GRAPH_TARGET: Color = Color::srgb(0.9, 0.4, 0.4);
     pub const GRAPH_GRID: Color = Color::srgba(0.4, 0.4, 0.45, 0.3);
+    pub const GRAPH_WARMUP: Color = Color::srgb(0.45, 0.47, 0.5);
 }
 
 /// UI Sizing
@@ -73,3 +1047,120 @@ pub mod sizes {
     pub const FONT_SIZE_SMALL: f32 = 13.0;
     pub const FONT_SIZE_LARGE_METRIC: f32 = 48.0;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_left_alone() {
+        let mut config = BenchmarkConfig::default();
+        config.validate();
+        assert_eq!(config.sample_frames, SAMPLE_FRAMES);
+        assert_eq!(config.default_target_ms, TARGET_FRAME_TIME_MS);
+        assert_eq!(config.churn_rate, DEFAULT_CHURN_RATE);
+        assert_eq!(config.percentiles, DEFAULT_PERCENTILES.to_vec());
+    }
+
+    #[test]
+    fn zero_sample_frames_and_sub_windows_become_one() {
+        let mut config = BenchmarkConfig {
+            sample_frames: 0,
+            sample_sub_windows: 0,
+            ..default()
+        };
+        config.validate();
+        assert_eq!(config.sample_frames, 1);
+        assert_eq!(config.sample_sub_windows, 1);
+    }
+
+    #[test]
+    fn negative_or_nan_targets_fall_back() {
+        let mut config = BenchmarkConfig {
+            default_target_ms: -5.0,
+            ..default()
+        };
+        config
+            .workload_targets
+            .insert(SelectedWorkload::SimpleIteration, f64::NAN);
+        config.validate();
+        assert_eq!(config.default_target_ms, TARGET_FRAME_TIME_MS);
+        assert_eq!(
+            config.target_frame_time_ms(SelectedWorkload::SimpleIteration),
+            TARGET_FRAME_TIME_MS
+        );
+
+        let mut config = BenchmarkConfig {
+            default_target_ms: f64::NAN,
+            ..default()
+        };
+        config.validate();
+        assert_eq!(config.default_target_ms, TARGET_FRAME_TIME_MS);
+    }
+
+    #[test]
+    fn nan_churn_falls_back_and_out_of_range_churn_is_clamped() {
+        let mut config = BenchmarkConfig {
+            churn_rate: f32::NAN,
+            ..default()
+        };
+        config.validate();
+        assert_eq!(config.churn_rate, DEFAULT_CHURN_RATE);
+
+        config.churn_rate = 2.0;
+        config.validate();
+        assert_eq!(config.churn_rate, MAX_CHURN_RATE);
+    }
+
+    #[test]
+    fn zero_toggle_period_becomes_one() {
+        let mut config = BenchmarkConfig {
+            toggle_period: 0,
+            ..default()
+        };
+        config.validate();
+        assert_eq!(config.toggle_period, 1);
+    }
+
+    #[test]
+    fn tiny_fragment_weights_still_split_to_the_exact_count() {
+        let distribution = FragmentDistribution::Weighted(vec![1.0, 1e-8]);
+        for count in [0, 1, 999, 1_000_000_000] {
+            let counts = distribution.split(count, 2);
+            assert_eq!(counts.iter().sum::<usize>(), count, "{:?}", counts);
+        }
+    }
+
+    #[test]
+    fn toggle_settings_label_the_variant_only_when_changed() {
+        let mut config = BenchmarkConfig::default();
+        assert_eq!(
+            config.variant_name(SelectedWorkload::ComponentAddRemove),
+            None
+        );
+
+        config.toggle_fraction = 0.1;
+        config.toggle_period = 1;
+        config.toggle_max = None;
+        assert_eq!(
+            config
+                .variant_name(SelectedWorkload::ComponentAddRemove)
+                .as_deref(),
+            Some("10% / 1f / cap none")
+        );
+    }
+
+    #[test]
+    fn percentiles_are_filtered_deduplicated_and_never_empty() {
+        let mut config = BenchmarkConfig {
+            percentiles: vec![99.0, f64::NAN, 50.0, 99.0, 150.0, 0.0],
+            ..default()
+        };
+        config.validate();
+        assert_eq!(config.percentiles, vec![99.0, 50.0]);
+
+        config.percentiles = vec![-1.0, f64::INFINITY];
+        config.validate();
+        assert_eq!(config.percentiles, DEFAULT_PERCENTILES.to_vec());
+    }
+}
@@ -5,9 +5,19 @@
 //! Run with: `cargo run --release`
 
 use bevy::prelude::*;
+use bevy_ecs_benchmark::config::BenchmarkConfig;
 use bevy_ecs_benchmark::BenchmarkPlugin;
 
 fn main() {
+    // e.g. `cargo run --release -- --workloads 2,5`
+    let config = match BenchmarkConfig::default().with_args(std::env::args().skip(1)) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
@@ -18,6 +28,7 @@ fn main() {
             }),
             ..default()
         }))
+        .insert_resource(config)
         .add_plugins(BenchmarkPlugin)
         .run();
 }
@@ -0,0 +1,86 @@
+//! Thermal drift detection for long automated suites.
+//!
+//! There are no temperature sensors involved: the monitor times a fixed
+//! amount of work in an empty world before the suite and again between
+//! workloads. If the same work gets noticeably slower, the machine has
+//! most likely started throttling and later results are not comparable
+//! with earlier ones.
+
+use bevy::platform::time::Instant;
+use bevy::prelude::*;
+use std::hint::black_box;
+
+use crate::config::{DRIFT_BASELINE_SAMPLES, DRIFT_CALIBRATION_ITERATIONS};
+
+/// Tracks the empty-world baseline across an automated suite
+#[derive(Resource, Default, Debug, Clone)]
+pub struct DriftMonitor {
+    /// Baseline median (ms) measured before the suite started
+    pub baseline_ms: Option<f64>,
+    /// Most recent re-measurement (ms)
+    pub latest_ms: Option<f64>,
+    /// Largest rise over the baseline seen so far, in percent
+    pub max_drift_percent: f64,
+    /// Set once the drift exceeds the configured threshold
+    pub drift_detected: bool,
+}
+
+impl DriftMonitor {
+    /// Measure a fresh baseline and clear any previous detection
+    pub fn start(&mut self) {
+        *self = Self {
+            baseline_ms: Some(measure_empty_world_median_ms()),
+            ..Self::default()
+        };
+    }
+
+    /// Re-measure and compare against the baseline.
+    ///
+    /// Returns the current drift in percent, or `None` if no baseline
+    /// has been taken. `drift_detected` stays set once tripped.
+    pub fn check(&mut self, threshold_percent: f64) -> Option<f64> {
+        let baseline = self.baseline_ms?;
+        let latest = measure_empty_world_median_ms();
+        let drift = (latest - baseline) / baseline * 100.0;
+
+        self.latest_ms = Some(latest);
+        self.max_drift_percent = self.max_drift_percent.max(drift);
+        if drift > threshold_percent {
+            self.drift_detected = true;
+        }
+        Some(drift)
+    }
+}
+
+/// Fixed CPU-bound work run by the calibration schedule
+fn calibration_system() {
+    let mut acc: u64 = 0;
+    for i in 0..DRIFT_CALIBRATION_ITERATIONS {
+        acc = black_box(acc.wrapping_mul(6364136223846793005).wrapping_add(i));
+    }
+    black_box(acc);
+}
+
+/// Median time (ms) to run a calibration schedule on an empty world.
+///
+/// Uses a standalone `World` so the benchmark world's entities and
+/// archetypes don't affect the measurement.
+pub fn measure_empty_world_median_ms() -> f64 {
+    let mut world = World::new();
+    let mut schedule = Schedule::default();
+    schedule.add_systems(calibration_system);
+
+    // First run initializes the schedule; keep it out of the samples
+    schedule.run(&mut world);
+
+    let mut samples: Vec<f64> = (0..DRIFT_BASELINE_SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            schedule.run(&mut world);
+            start.elapsed().as_secs_f64() * 1000.0
+        })
+        .collect();
+
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    samples[samples.len() / 2]
+}
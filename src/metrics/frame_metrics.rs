@@ -1,79 +1,234 @@
 //! Frame timing metrics collection and analysis.
 
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
-use crate::config::{FRAME_HISTORY_LENGTH, SAMPLE_FRAMES};
+use crate::config::{
+    TimingSource, COMMON_REFRESH_RATES, FRAME_HISTORY_LENGTH, SAMPLE_FRAMES,
+    VSYNC_DETECTION_FRAMES, VSYNC_DETECTION_SKIP_FRAMES, VSYNC_TOLERANCE,
+};
+use crate::state::{AppState, BenchmarkPhase};
 
 /// Resource tracking frame timing metrics
 #[derive(Resource)]
 pub struct FrameMetrics {
     /// Rolling history of frame times (in milliseconds)
     pub frame_times: VecDeque<f64>,
+    /// Phase each entry in `frame_times` was captured in (parallel to it)
+    pub frame_phases: VecDeque<BenchmarkPhase>,
+    /// Throughput (entities per second) of each entry in `frame_times` (parallel to it)
+    pub throughput_history: VecDeque<f64>,
     /// Current frame time
     pub current_frame_time: f64,
     /// Samples collected for current measurement period
     pub samples: Vec<f64>,
     /// Current throughput (entities per second)
     pub throughput: f64,
+    /// Structural operations (spawns + despawns, inserts + removes) per second
+    /// in the last frame that performed any
+    pub structural_ops_per_sec: f64,
+    /// Structural operations since sampling started
+    pub window_structural_ops: usize,
+    /// Seconds elapsed since sampling started
+    pub window_secs: f64,
+    /// Whether the empty-world frame time looks capped by vsync
+    pub vsync_suspected: bool,
+    /// Start of the in-progress workload span (`TimingSource::WorkloadSpan`)
+    pub span_start: Option<Instant>,
+    /// Duration of the last completed workload span (in seconds)
+    pub last_span_secs: f64,
+    /// Non-finite samples discarded by `add_sample` since the last reset
+    pub dropped_samples: usize,
+    /// Maximum entries kept in `frame_times`
+    pub history_length: usize,
 }
 
 impl Default for FrameMetrics {
     fn default() -> Self {
+        Self::with_history_length(FRAME_HISTORY_LENGTH)
+    }
+}
+
+impl FrameMetrics {
+    /// Metrics keeping the last `history_length` frames (at least 1)
+    pub fn with_history_length(history_length: usize) -> Self {
+        let history_length = history_length.max(1);
         Self {
-            frame_times: VecDeque::with_capacity(FRAME_HISTORY_LENGTH),
+            frame_times: VecDeque::with_capacity(history_length),
+            frame_phases: VecDeque::with_capacity(history_length),
+            throughput_history: VecDeque::with_capacity(history_length),
             current_frame_time: 0.0,
             samples: Vec::with_capacity(SAMPLE_FRAMES),
             throughput: 0.0,
+            structural_ops_per_sec: 0.0,
+            window_structural_ops: 0,
+            window_secs: 0.0,
+            vsync_suspected: false,
+            span_start: None,
+            last_span_secs: 0.0,
+            dropped_samples: 0,
+            history_length,
         }
     }
-}
 
-impl FrameMetrics {
     /// Record a new frame time
-    pub fn record_frame(&mut self, delta_seconds: f64, entity_count: usize) {
+    pub fn record_frame(&mut self, delta_seconds: f64, entity_count: usize, phase: BenchmarkPhase) {
         let frame_time_ms = delta_seconds * 1000.0;
         self.current_frame_time = frame_time_ms;
 
         // Update rolling history
-        if self.frame_times.len() >= FRAME_HISTORY_LENGTH {
+        if self.frame_times.len() >= self.history_length {
             self.frame_times.pop_front();
+            self.frame_phases.pop_front();
+            self.throughput_history.pop_front();
         }
         self.frame_times.push_back(frame_time_ms);
+        self.frame_phases.push_back(phase);
 
         // Calculate throughput
-        if delta_seconds > 0.0 {
+        if delta_seconds > 0.0 && delta_seconds.is_finite() {
             self.throughput = entity_count as f64 / delta_seconds;
         }
+        self.throughput_history.push_back(self.throughput);
+    }
+
+    /// Mark the start of the benchmark systems for this frame
+    pub fn begin_span(&mut self) {
+        self.span_start = Some(Instant::now());
+    }
+
+    /// Mark the end of the benchmark systems for this frame
+    pub fn end_span(&mut self) {
+        if let Some(start) = self.span_start.take() {
+            self.last_span_secs = start.elapsed().as_secs_f64();
+        }
+    }
+
+    /// Seconds to record for this frame according to the timing source
+    pub fn measured_secs(&self, time: &Time, source: TimingSource) -> f64 {
+        match source {
+            TimingSource::FrameDelta => time.delta_secs_f64(),
+            TimingSource::WorkloadSpan => self.last_span_secs,
+        }
+    }
+
+    /// Record structural operations performed this frame
+    pub fn record_structural_ops(&mut self, ops: usize, delta_seconds: f64) {
+        self.window_structural_ops += ops;
+        if delta_seconds > 0.0 {
+            self.structural_ops_per_sec = ops as f64 / delta_seconds;
+        }
+    }
+
+    /// Add a sampled frame's duration to the structural ops window
+    pub fn add_window_time(&mut self, delta_seconds: f64) {
+        if delta_seconds.is_finite() && delta_seconds > 0.0 {
+            self.window_secs += delta_seconds;
+        }
+    }
+
+    /// Structural operations per second over the whole sampling window.
+    ///
+    /// Unlike `structural_ops_per_sec`, frames without any operations count
+    /// too, so workloads that change structure in bursts aren't overstated.
+    pub fn window_structural_ops_per_sec(&self) -> f64 {
+        if self.window_secs > 0.0 {
+            self.window_structural_ops as f64 / self.window_secs
+        } else {
+            0.0
+        }
     }
 
-    /// Add a sample for the current measurement period
+    /// Add a sample for the current measurement period.
+    ///
+    /// NaN and infinite samples are dropped (and counted) rather than
+    /// stored, since they would corrupt the sort in `sample_stats`.
     pub fn add_sample(&mut self, delta_seconds: f64) {
-        self.samples.push(delta_seconds * 1000.0);
+        let sample_ms = delta_seconds * 1000.0;
+        if sample_ms.is_finite() {
+            self.samples.push(sample_ms);
+        } else {
+            self.dropped_samples += 1;
+        }
+    }
+
+    /// Slowest sample collected so far and its index in the window
+    pub fn worst_sample(&self) -> Option<(usize, f64)> {
+        self.samples
+            .iter()
+            .copied()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+    }
+
+    /// Median of the samples collected so far (0.0 if none)
+    pub fn running_median(&self) -> f64 {
+        self.running_percentile(50.0)
+    }
+
+    /// Percentile of the samples collected so far (0.0 if none)
+    pub fn running_percentile(&self, percentile: f64) -> f64 {
+        if self.samples.is_empty() {
+            return 0.0;
+        }
+        let mut sorted = self.samples.clone();
+        sorted.sort_by(f64::total_cmp);
+        let idx = ((sorted.len() as f64) * percentile / 100.0) as usize;
+        sorted[idx.min(sorted.len() - 1)]
+    }
+
+    /// Jitter of the samples collected so far (0.0 with fewer than two)
+    pub fn running_jitter(&self) -> f64 {
+        mean_abs_delta(&self.samples)
+    }
+
+    /// Coefficient of variation of the last `window` frames, if they were all
+    /// captured in `phase` (None until enough such frames are recorded)
+    pub fn trailing_cv(&self, window: usize, phase: BenchmarkPhase) -> Option<f64> {
+        if window == 0 || self.frame_phases.len() < window {
+            return None;
+        }
+        let start = self.frame_times.len() - window;
+        if self.frame_phases.range(start..).any(|&p| p != phase) {
+            return None;
+        }
+
+        let recent: Vec<f64> = self.frame_times.range(start..).copied().collect();
+        let mean = recent.iter().sum::<f64>() / window as f64;
+        if mean <= 0.0 {
+            return None;
+        }
+        let variance = recent.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / window as f64;
+        Some(variance.sqrt() / mean)
     }
 
     /// Clear collected samples
     pub fn clear_samples(&mut self) {
         self.samples.clear();
+        self.window_structural_ops = 0;
+        self.window_secs = 0.0;
     }
 
-    /// Get statistics from collected samples
-    pub fn sample_stats(&self) -> SampleStats {
+    /// Get statistics from collected samples, including the given percentiles.
+    ///
+    /// The samples are also split into `sub_windows` consecutive chunks with
+    /// a median each, so a window that started at an unlucky point in the
+    /// frame schedule or drifted part-way through shows up.
+    pub fn sample_stats(&self, percentiles: &[f64], sub_windows: usize) -> SampleStats {
         if self.samples.is_empty() {
-            return SampleStats::default();
+            return SampleStats {
+                dropped: self.dropped_samples,
+                ..SampleStats::default()
+            };
         }
 
         let mut sorted = self.samples.clone();
-        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        sorted.sort_by(f64::total_cmp);
 
         let min = sorted.first().copied().unwrap_or(0.0);
         let max = sorted.last().copied().unwrap_or(0.0);
-        let median = if sorted.len() % 2 == 0 {
-            let mid = sorted.len() / 2;
-            (sorted[mid - 1] + sorted[mid]) / 2.0
-        } else {
-            sorted[sorted.len() / 2]
-        };
+        let median = sorted_median(&sorted);
         let mean = self.samples.iter().sum::<f64>() / self.samples.len() as f64;
 
         // Calculate standard deviation
@@ -82,21 +237,48 @@ impl FrameMetrics {
             .sum::<f64>() / self.samples.len() as f64;
         let std_dev = variance.sqrt();
 
-        // Percentiles
-        let p95_idx = ((sorted.len() as f64) * 0.95) as usize;
-        let p99_idx = ((sorted.len() as f64) * 0.99) as usize;
-        let p95 = sorted.get(p95_idx.min(sorted.len() - 1)).copied().unwrap_or(0.0);
-        let p99 = sorted.get(p99_idx.min(sorted.len() - 1)).copied().unwrap_or(0.0);
+        // Percentiles (p95/p99 always computed for the convenience accessors)
+        let percentiles = percentiles
+            .iter()
+            .chain(&[95.0, 99.0])
+            .map(|&p| {
+                let idx = ((sorted.len() as f64) * p / 100.0) as usize;
+                let value = sorted
+                    .get(idx.min(sorted.len() - 1))
+                    .copied()
+                    .unwrap_or(0.0);
+                (percentile_key(p), value)
+            })
+            .collect();
+
+        // Fewer samples than sub-windows (early abort) means fewer chunks
+        let sub_window_medians = if sub_windows > 1 {
+            let chunk_len = self.samples.len().div_ceil(sub_windows);
+            self.samples
+                .chunks(chunk_len)
+                .map(|chunk| {
+                    let mut chunk = chunk.to_vec();
+                    chunk.sort_by(f64::total_cmp);
+                    sorted_median(&chunk)
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
 
         SampleStats {
             min,
             max,
             median,
+            sub_window_medians,
             mean,
             std_dev,
-            p95,
-            p99,
+            // Consecutive frames only make sense in collection order, so
+            // this reads `samples` rather than `sorted`
+            jitter_ms: mean_abs_delta(&self.samples),
+            percentiles,
             count: self.samples.len(),
+            dropped: self.dropped_samples,
         }
     }
 
@@ -125,12 +307,55 @@ impl FrameMetrics {
         &self.frame_times
     }
 
+    /// Get the phase of each recorded frame for graphing
+    pub fn frame_phases_slice(&self) -> &VecDeque<BenchmarkPhase> {
+        &self.frame_phases
+    }
+
+    /// Get the throughput of each recorded frame for graphing
+    pub fn throughput_history_slice(&self) -> &VecDeque<f64> {
+        &self.throughput_history
+    }
+
     /// Reset all metrics
     pub fn reset(&mut self) {
         self.frame_times.clear();
+        self.frame_phases.clear();
+        self.throughput_history.clear();
         self.current_frame_time = 0.0;
         self.samples.clear();
         self.throughput = 0.0;
+        self.structural_ops_per_sec = 0.0;
+        self.window_structural_ops = 0;
+        self.window_secs = 0.0;
+        self.span_start = None;
+        self.last_span_secs = 0.0;
+        self.dropped_samples = 0;
+    }
+}
+
+/// Mean absolute difference between consecutive values (0.0 with fewer than two)
+fn mean_abs_delta(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return 0.0;
+    }
+    let total: f64 = values
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).abs())
+        .sum();
+    total / (values.len() - 1) as f64
+}
+
+/// Median of already sorted values (0.0 if empty)
+fn sorted_median(sorted: &[f64]) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
     }
 }
 
@@ -140,17 +365,67 @@ pub struct SampleStats {
     pub min: f64,
     pub max: f64,
     pub median: f64,
+    /// Median of each consecutive sub-window, in order (empty with one window)
+    pub sub_window_medians: Vec<f64>,
     pub mean: f64,
     pub std_dev: f64,
-    pub p95: f64,
-    pub p99: f64,
+    /// Mean absolute difference between consecutive samples: stutter,
+    /// independent of how long the frames are
+    pub jitter_ms: f64,
+    /// Percentile values keyed by basis points (see `percentile_key`)
+    pub percentiles: HashMap<u32, f64>,
     pub count: usize,
+    /// Non-finite samples discarded while collecting
+    pub dropped: usize,
+}
+
+/// Map key for a percentile in basis points (99.9 -> 9990)
+pub fn percentile_key(percentile: f64) -> u32 {
+    (percentile * 100.0).round() as u32
 }
 
 impl SampleStats {
-    /// Check if the median exceeds a target
+    /// Get a computed percentile (0.0 if it wasn't requested)
+    pub fn percentile(&self, percentile: f64) -> f64 {
+        self.percentiles
+            .get(&percentile_key(percentile))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// 95th percentile frame time
+    pub fn p95(&self) -> f64 {
+        self.percentile(95.0)
+    }
+
+    /// 99th percentile frame time
+    pub fn p99(&self) -> f64 {
+        self.percentile(99.0)
+    }
+
+    /// Coefficient of variation (`std_dev / mean`); lower is more stable
+    pub fn cv(&self) -> f64 {
+        if self.mean > 0.0 {
+            self.std_dev / self.mean
+        } else {
+            0.0
+        }
+    }
+
+    /// The search's decision statistic: the median of the sub-window
+    /// medians, or the plain median without sub-windows
+    pub fn decision_median(&self) -> f64 {
+        if self.sub_window_medians.is_empty() {
+            return self.median;
+        }
+        let mut medians = self.sub_window_medians.clone();
+        medians.sort_by(f64::total_cmp);
+        sorted_median(&medians)
+    }
+
+    /// Check if the decision median exceeds a target
     pub fn median_exceeds(&self, target_ms: f64) -> bool {
-        self.median > target_ms
+        self.decision_median() > target_ms
     }
 }
 
@@ -160,7 +435,65 @@ pub fn update_frame_metrics(
     mut metrics: ResMut<FrameMetrics>,
     // We need some way to know entity count - this will be passed differently per workload
 ) {
-    metrics.record_frame(time.delta_secs_f64(), 0);
+    metrics.record_frame(time.delta_secs_f64(), 0, BenchmarkPhase::Idle);
+}
+
+/// Check whether a frame time sits on a common display refresh interval
+pub fn is_near_refresh_interval(frame_time_ms: f64) -> bool {
+    COMMON_REFRESH_RATES.iter().any(|hz| {
+        let interval_ms = 1000.0 / hz;
+        ((frame_time_ms - interval_ms) / interval_ms).abs() < VSYNC_TOLERANCE
+    })
+}
+
+/// Sample the empty world at startup and warn if frame times look vsync-capped.
+///
+/// `PresentMode::AutoNoVsync` can still end up vsync-limited on some drivers,
+/// which silently caps every workload at the refresh interval. An empty world
+/// should run far faster than any display refresh rate, so a median landing
+/// on one is a strong hint.
+pub fn detect_vsync(
+    time: Res<Time>,
+    app_state: Res<State<AppState>>,
+    mut metrics: ResMut<FrameMetrics>,
+    mut frame: Local<usize>,
+    mut samples: Local<Vec<f64>>,
+    mut done: Local<bool>,
+) {
+    if *done {
+        return;
+    }
+
+    // Only meaningful before any benchmark entities exist
+    if *app_state.get() != AppState::Menu {
+        *done = true;
+        return;
+    }
+
+    *frame += 1;
+    if *frame <= VSYNC_DETECTION_SKIP_FRAMES {
+        return;
+    }
+
+    samples.push(time.delta_secs_f64() * 1000.0);
+    if samples.len() < VSYNC_DETECTION_FRAMES {
+        return;
+    }
+
+    *done = true;
+    samples.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let median = samples[samples.len() / 2];
+
+    if is_near_refresh_interval(median) {
+        metrics.vsync_suspected = true;
+        warn!(
+            "Empty-world median frame time is {:.2}ms, matching a display refresh interval. \
+             Vsync is probably capping measurements; results will be unreliable.",
+            median
+        );
+    } else {
+        info!("Vsync check passed (empty-world median {:.2}ms)", median);
+    }
 }
 
 /// Formats a number with appropriate suffix (K, M, B)
@@ -188,3 +521,28 @@ pub fn format_throughput(eps: f64) -> String {
         format!("{:.0}/s", eps)
     }
 }
+
+/// Formats a byte count with a binary suffix (KiB, MiB, GiB)
+pub fn format_bytes(bytes: usize) -> String {
+    const KIB: f64 = 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= KIB * KIB * KIB {
+        format!("{:.2}GiB", bytes / (KIB * KIB * KIB))
+    } else if bytes >= KIB * KIB {
+        format!("{:.1}MiB", bytes / (KIB * KIB))
+    } else if bytes >= KIB {
+        format!("{:.1}KiB", bytes / KIB)
+    } else {
+        format!("{}B", bytes)
+    }
+}
+
+/// Formats a duration in seconds as `Xs` or `Xm YYs`
+pub fn format_duration(seconds: f64) -> String {
+    let total = seconds.max(0.0).round() as u64;
+    if total >= 60 {
+        format!("{}m {:02}s", total / 60, total % 60)
+    } else {
+        format!("{:.1}s", seconds.max(0.0))
+    }
+}
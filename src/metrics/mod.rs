@@ -1,5 +1,13 @@
 //! Performance measurement utilities.
 
+mod drift;
 mod frame_metrics;
+mod parallelism;
+mod prometheus;
+mod world_stats;
 
+pub use drift::*;
 pub use frame_metrics::*;
+pub use parallelism::*;
+pub use prometheus::*;
+pub use world_stats::*;
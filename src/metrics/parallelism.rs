@@ -0,0 +1,114 @@
+//! Best-effort view of how many worker threads parallel iteration uses.
+
+use bevy::prelude::*;
+use bevy::tasks::ComputeTaskPool;
+use bevy::utils::Parallel;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::components::BenchmarkEntity;
+
+/// Resource holding the outcome of the last parallelism probe
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct ParallelismStats {
+    /// Distinct threads that ran part of the probe's `par_iter`
+    pub threads_used: usize,
+    /// Threads in the compute task pool
+    pub pool_threads: usize,
+}
+
+/// Run a `par_iter` over the benchmark entities and count the threads it touched.
+///
+/// Bevy doesn't expose which threads a system's `par_iter_mut` ran on, so
+/// this mirrors the workloads' batching with a separate no-op pass. Marking
+/// a thread-local flag per entity isn't free, so it is skipped during
+/// WarmUp and Sampling; entity counts don't change between those phases,
+/// so the last reading still applies. Small counts fit in one batch and
+/// show 1 thread, which is why tiny workloads don't speed up in parallel.
+pub fn probe_parallelism(
+    query: Query<(), With<BenchmarkEntity>>,
+    mut touched: Local<Parallel<bool>>,
+    mut stats: ResMut<ParallelismStats>,
+) {
+    for flag in touched.iter_mut() {
+        *flag = false;
+    }
+
+    query.par_iter().for_each(|_| {
+        touched.scope(|flag| *flag = true);
+    });
+
+    stats.threads_used = touched.iter_mut().filter(|flag| **flag).count();
+    stats.pool_threads = ComputeTaskPool::try_get()
+        .map(|pool| pool.thread_num())
+        .unwrap_or(1);
+}
+
+/// How one frame's `par_iter_mut` calls in the mutation workloads were split up.
+///
+/// Armed for a single warm-up frame per search iteration; the instrumented
+/// systems read it through `Res` so they still don't conflict. Each batch
+/// Bevy hands to a task calls the `for_each_init` initializer once, which
+/// gives the task count; each closure invocation bumps a thread-local
+/// counter, which gives the per-thread distribution.
+#[derive(Resource, Default)]
+pub struct ParIterProbe {
+    armed: bool,
+    tasks: AtomicUsize,
+    per_thread: Parallel<usize>,
+}
+
+/// Result of one armed frame
+#[derive(Debug, Clone, Default)]
+pub struct ParIterSample {
+    /// Batches (tasks) the work was split into
+    pub tasks: usize,
+    /// Closure invocations on each thread that got work, largest first
+    pub per_thread: Vec<usize>,
+}
+
+impl ParIterProbe {
+    /// Record the next frame's `par_iter_mut` calls
+    pub fn arm(&mut self) {
+        self.armed = true;
+        self.tasks.store(0, Ordering::Relaxed);
+        for count in self.per_thread.iter_mut() {
+            *count = 0;
+        }
+    }
+
+    pub fn disarm(&mut self) {
+        self.armed = false;
+    }
+
+    pub fn is_armed(&self) -> bool {
+        self.armed
+    }
+
+    /// `for_each_init` initializer: count one task
+    pub fn begin_task(&self) {
+        self.tasks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Count one closure invocation on the current thread
+    pub fn record(&self) {
+        self.per_thread.scope(|count| *count += 1);
+    }
+
+    /// Disarm and return the recorded frame, if an instrumented system ran
+    pub fn take(&mut self) -> Option<ParIterSample> {
+        let tasks = *self.tasks.get_mut();
+        if !self.armed || tasks == 0 {
+            return None;
+        }
+        self.armed = false;
+
+        let mut per_thread: Vec<usize> = self
+            .per_thread
+            .iter_mut()
+            .map(|count| *count)
+            .filter(|&count| count > 0)
+            .collect();
+        per_thread.sort_unstable_by(|a, b| b.cmp(a));
+        Some(ParIterSample { tasks, per_thread })
+    }
+}
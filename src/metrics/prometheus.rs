@@ -0,0 +1,151 @@
+//! Optional Prometheus text endpoint for scraping live metrics (`--metrics-port`).
+
+use bevy::prelude::*;
+use std::fmt::Write as _;
+use std::io::{self, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::metrics::FrameMetrics;
+use crate::state::{BenchmarkPhase, BenchmarkState, SelectedWorkload};
+
+/// Phases reported by the `bench_phase` gauge, one series each
+const PHASES: [BenchmarkPhase; 6] = [
+    BenchmarkPhase::Idle,
+    BenchmarkPhase::WarmUp,
+    BenchmarkPhase::Sampling,
+    BenchmarkPhase::Adjusting,
+    BenchmarkPhase::Complete,
+    BenchmarkPhase::Cooldown,
+];
+
+/// Values served to scrapers, copied from the ECS once per frame
+#[derive(Debug, Clone, Default)]
+pub struct MetricsSnapshot {
+    pub frame_time_ms: f64,
+    pub throughput_eps: f64,
+    pub structural_ops_per_sec: f64,
+    pub entity_count: usize,
+    pub phase: BenchmarkPhase,
+    pub workload: &'static str,
+}
+
+impl MetricsSnapshot {
+    /// Render in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        let mut gauge = |name: &str, help: &str, value: f64| {
+            let _ = writeln!(out, "# HELP {} {}", name, help);
+            let _ = writeln!(out, "# TYPE {} gauge", name);
+            let _ = writeln!(out, "{}{{workload=\"{}\"}} {}", name, self.workload, value);
+        };
+        gauge(
+            "bench_frame_time_ms",
+            "Last frame time in milliseconds",
+            self.frame_time_ms,
+        );
+        gauge(
+            "bench_throughput_eps",
+            "Entities processed per second",
+            self.throughput_eps,
+        );
+        gauge(
+            "bench_structural_ops_per_sec",
+            "Spawns, despawns, inserts and removes per second",
+            self.structural_ops_per_sec,
+        );
+        gauge(
+            "bench_entity_count",
+            "Entity count being measured",
+            self.entity_count as f64,
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP bench_phase Current benchmark phase (1 = active)"
+        );
+        let _ = writeln!(out, "# TYPE bench_phase gauge");
+        for phase in PHASES {
+            let _ = writeln!(
+                out,
+                "bench_phase{{phase=\"{:?}\"}} {}",
+                phase,
+                u8::from(phase == self.phase)
+            );
+        }
+        out
+    }
+}
+
+/// Snapshot shared between the app and the server thread.
+///
+/// Only inserted when `BenchmarkConfig::metrics_port` is set and the
+/// listener could be bound.
+#[derive(Resource, Clone, Default)]
+pub struct SharedMetrics(pub Arc<Mutex<MetricsSnapshot>>);
+
+impl SharedMetrics {
+    /// Bind `port` on localhost (every interface if `bind_all`) and serve
+    /// the snapshot from a background thread
+    pub fn serve(port: u16, bind_all: bool) -> io::Result<Self> {
+        let host = if bind_all { "0.0.0.0" } else { "127.0.0.1" };
+        let listener = TcpListener::bind((host, port))?;
+        let shared = Self::default();
+        let snapshot = shared.0.clone();
+
+        std::thread::Builder::new()
+            .name("metrics-server".to_string())
+            .spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    let body = snapshot
+                        .lock()
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .to_prometheus();
+                    // A failed scrape only affects that client
+                    let _ = respond(stream, &body);
+                }
+            })?;
+
+        Ok(shared)
+    }
+}
+
+/// Answer any request with the metrics body and close the connection
+fn respond(mut stream: TcpStream, body: &str) -> io::Result<()> {
+    // The request itself is ignored: every path serves the metrics. The
+    // timeout keeps a silent client from blocking the next scrape.
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    let mut request = [0u8; 1024];
+    let _ = stream.read(&mut request)?;
+
+    write!(
+        stream,
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+/// Copy this frame's values into the shared snapshot
+pub fn update_metrics_snapshot(
+    shared: Res<SharedMetrics>,
+    metrics: Res<FrameMetrics>,
+    state: Res<BenchmarkState>,
+    phase: Res<State<BenchmarkPhase>>,
+    workload: Res<SelectedWorkload>,
+) {
+    let mut snapshot = shared
+        .0
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    *snapshot = MetricsSnapshot {
+        frame_time_ms: metrics.current_frame_time,
+        throughput_eps: metrics.throughput,
+        structural_ops_per_sec: metrics.structural_ops_per_sec,
+        entity_count: state.entity_count,
+        phase: *phase.get(),
+        workload: workload.name(),
+    };
+}
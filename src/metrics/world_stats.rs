@@ -0,0 +1,76 @@
+//! Live ECS world structure statistics.
+
+use bevy::ecs::archetype::Archetypes;
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use std::collections::HashSet;
+
+/// Resource holding world structure counts, refreshed every frame
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct WorldStats {
+    /// Number of archetypes (unique component combinations)
+    pub archetype_count: usize,
+    /// Archetypes currently holding at least one entity
+    pub populated_archetype_count: usize,
+    /// Number of distinct tables backing those archetypes
+    pub table_count: usize,
+    /// Total live entities, including UI and camera entities
+    pub entity_count: usize,
+}
+
+/// Refresh `WorldStats` from the world's archetype and entity metadata.
+///
+/// Archetypes are never removed once created, so the count only grows
+/// during a session. Fragmented spawns add one archetype per variant
+/// combination; the unified `EntityVariant` approach adds just one.
+pub fn update_world_stats(
+    archetypes: &Archetypes,
+    entities: &Entities,
+    mut stats: ResMut<WorldStats>,
+) {
+    let tables: HashSet<_> = archetypes.iter().map(|a| a.table_id()).collect();
+
+    stats.archetype_count = archetypes.len();
+    stats.populated_archetype_count = archetypes.iter().filter(|a| !a.is_empty()).count();
+    stats.table_count = tables.len();
+    stats.entity_count = entities.len() as usize;
+}
+
+/// A to log every populated archetype's components and entity count, largest first.
+///
+/// Exclusive so it sees the world as of the key press, with every command
+/// from earlier systems applied. Useful for checking that the fragmentation
+/// workloads really spread entities over the archetypes they are meant to.
+pub fn log_archetype_breakdown(world: &mut World) {
+    let components = world.components();
+    let mut rows: Vec<(usize, String)> = world
+        .archetypes()
+        .iter()
+        .filter(|archetype| !archetype.is_empty())
+        .map(|archetype| {
+            let names: Vec<String> = archetype
+                .components()
+                .map(|id| {
+                    components.get_info(id).map_or_else(
+                        || format!("{:?}", id),
+                        |info| info.name().shortname().to_string(),
+                    )
+                })
+                .collect();
+            (archetype.entities().len(), names.join(", "))
+        })
+        .collect();
+    rows.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+
+    let total: usize = rows.iter().map(|(count, _)| count).sum();
+    let mut out = format!(
+        "Archetype breakdown: {} populated of {} archetypes, {} entities",
+        rows.len(),
+        world.archetypes().len(),
+        total
+    );
+    for (count, names) in &rows {
+        out.push_str(&format!("\n{:>10}  [{}]", count, names));
+    }
+    info!("{}", out);
+}
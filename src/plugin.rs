@@ -1,14 +1,19 @@
 //! Main benchmark plugin that coordinates all subsystems.
 
+use bevy::input::common_conditions::input_just_pressed;
 use bevy::prelude::*;
 
-use crate::benchmark::results::{BenchmarkComplete, BenchmarkResults, SaveResultsRequest};
-use crate::benchmark::runner::{BenchmarkRunnerPlugin, DespawnAllRequest, SpawnEntitiesRequest};
+use crate::benchmark::results::{
+    BenchmarkComplete, BenchmarkResults, ReportComparison, SaveResultsRequest,
+};
+use crate::benchmark::runner::{
+    BenchmarkRunnerPlugin, DespawnAllRequest, PendingRespawn, SpawnEntitiesRequest, StepRequested,
+};
 use crate::benchmark::workloads::{ComponentToggleState, SpawnDespawnState, WorkloadsPlugin};
-use crate::components::BenchmarkEntity;
-use crate::config::TARGET_FRAME_TIME_MS;
-use crate::metrics::FrameMetrics;
-use crate::state::{AppState, BenchmarkPhase, BenchmarkState, SelectedWorkload};
+use crate::components::{BenchmarkEntity, FastRng};
+use crate::config::BenchmarkConfig;
+use crate::metrics::{detect_vsync, log_archetype_breakdown, DriftMonitor, FrameMetrics};
+use crate::state::{AppState, BenchmarkPhase, BenchmarkState, LastSession, SelectedWorkload};
 use crate::ui::BenchmarkUiPlugin;
 
 /// Main plugin for the benchmark suite
@@ -17,16 +22,10 @@ pub struct BenchmarkPlugin;
 impl Plugin for BenchmarkPlugin {
     fn build(&self, app: &mut App) {
         app
-            // States
-            .init_state::<AppState>()
-            .init_state::<BenchmarkPhase>()
-            // Resources
-            .init_resource::<SelectedWorkload>()
-            .init_resource::<BenchmarkState>()
-            .init_resource::<FrameMetrics>()
-            .init_resource::<BenchmarkResults>()
+            // Resources (states and core resources come from BenchmarkRunnerPlugin)
             .init_resource::<SpawnDespawnState>()
             .init_resource::<ComponentToggleState>()
+            .init_resource::<DriftMonitor>()
             // Events
             .add_message::<SaveResultsRequest>()
             // Sub-plugins
@@ -34,16 +33,104 @@ impl Plugin for BenchmarkPlugin {
             .add_plugins(WorkloadsPlugin)
             .add_plugins(BenchmarkUiPlugin)
             // Core systems
-            .add_systems(Startup, setup_camera)
+            .add_systems(
+                Startup,
+                (setup_camera, restore_last_session, load_report_comparison),
+            )
+            .add_systems(Last, (save_last_session_on_exit, save_results_on_exit))
             .add_systems(
                 Update,
                 (
                     handle_input,
                     update_metrics,
-                    handle_benchmark_complete,
+                    // After input so a cancel in the same frame stops the advance
+                    handle_benchmark_complete.after(handle_input),
                     handle_save_request,
+                    detect_vsync,
                 ),
+            )
+            // Only on request: walking every archetype is too slow to do each frame
+            .add_systems(
+                Update,
+                log_archetype_breakdown.run_if(input_just_pressed(KeyCode::KeyA)),
+            )
+            .add_systems(
+                Update,
+                (
+                    step_entity_count.run_if(
+                        input_just_pressed(KeyCode::ArrowUp)
+                            .or(input_just_pressed(KeyCode::ArrowDown)),
+                    ),
+                    request_phase_step
+                        .run_if(input_just_pressed(KeyCode::KeyN))
+                        .run_if(step_mode_enabled)
+                        .run_if(in_state(AppState::Running)),
+                )
+                    .after(handle_input),
+            )
+            // Sub-variant keys only act on the workload they belong to
+            .add_systems(
+                Update,
+                (
+                    change_churn_rate.run_if(selected_uses_churn_rate).run_if(
+                        input_just_pressed(KeyCode::BracketLeft)
+                            .or(input_just_pressed(KeyCode::BracketRight)),
+                    ),
+                    cycle_spawn_chunk_size
+                        .run_if(resource_equals(SelectedWorkload::ChunkedSpawn))
+                        .run_if(input_just_pressed(KeyCode::KeyC)),
+                    cycle_multi_component_count
+                        .run_if(resource_equals(SelectedWorkload::MultiComponentRead))
+                        .run_if(input_just_pressed(KeyCode::KeyW)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::BitflagBranching))
+                        .run_if(input_just_pressed(KeyCode::KeyB)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::ParallelPrepSpawn))
+                        .run_if(input_just_pressed(KeyCode::KeyP)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::LocalVsRes))
+                        .run_if(input_just_pressed(KeyCode::KeyL)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::BulkDespawn))
+                        .run_if(input_just_pressed(KeyCode::KeyX)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::DisjointParallel))
+                        .run_if(input_just_pressed(KeyCode::KeyK)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::IterStyle))
+                        .run_if(input_just_pressed(KeyCode::KeyF)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::EntityMapLookup))
+                        .run_if(input_just_pressed(KeyCode::KeyM)),
+                    toggle_variant
+                        .run_if(resource_equals(SelectedWorkload::RunConditionOverhead))
+                        .run_if(input_just_pressed(KeyCode::KeyQ)),
+                    // Locked while running or paused, as they change what is spawned
+                    (
+                        toggle_variant
+                            .run_if(resource_equals(SelectedWorkload::PositionIteration))
+                            .run_if(input_just_pressed(KeyCode::KeyO)),
+                        cycle_toggle_fraction
+                            .run_if(resource_equals(SelectedWorkload::ComponentAddRemove))
+                            .run_if(input_just_pressed(KeyCode::KeyU)),
+                        cycle_toggle_period
+                            .run_if(resource_equals(SelectedWorkload::ComponentAddRemove))
+                            .run_if(input_just_pressed(KeyCode::KeyY)),
+                        toggle_toggle_max
+                            .run_if(resource_equals(SelectedWorkload::ComponentAddRemove))
+                            .run_if(input_just_pressed(KeyCode::KeyZ)),
+                        cycle_hierarchy_depth
+                            .run_if(resource_equals(SelectedWorkload::RecursiveDespawn))
+                            .run_if(input_just_pressed(KeyCode::KeyH)),
+                    )
+                        .run_if(spawn_settings_unlocked),
+                )
+                    .after(handle_input),
             );
+
+        #[cfg(feature = "egui")]
+        app.add_plugins(crate::ui::EguiControlPanelPlugin);
     }
 }
 
@@ -52,6 +139,79 @@ fn setup_camera(mut commands: Commands) {
     commands.spawn(Camera2d);
 }
 
+/// Restore the workload and entity count from the previous launch.
+///
+/// A configured initial count (`--initial-count`) replaces the restored one.
+fn restore_last_session(
+    mut workload: ResMut<SelectedWorkload>,
+    mut state: ResMut<BenchmarkState>,
+    config: Res<BenchmarkConfig>,
+) {
+    if let Some(session) = LastSession::load() {
+        session.apply(&mut workload, &mut state);
+        info!(
+            "Restored last session: {} @ {} entities",
+            workload.name(),
+            state.entity_count
+        );
+    }
+    state.entity_count = state.initial_count(&config, *workload);
+}
+
+/// Load the reports passed with `--compare` and jump to the Results screen
+fn load_report_comparison(
+    mut commands: Commands,
+    config: Res<BenchmarkConfig>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+) {
+    let Some((path_a, path_b)) = &config.compare_reports else {
+        return;
+    };
+
+    match ReportComparison::load(path_a, path_b) {
+        Ok(comparison) => {
+            info!(
+                "Comparing {} against {} ({} workloads)",
+                comparison.label_b,
+                comparison.label_a,
+                comparison.rows.len()
+            );
+            commands.insert_resource(comparison);
+            next_app_state.set(AppState::Results);
+        }
+        Err(e) => error!("Failed to load reports for comparison: {}", e),
+    }
+}
+
+/// Remember the workload and entity count for the next launch
+fn save_last_session_on_exit(
+    mut exit_events: MessageReader<AppExit>,
+    workload: Res<SelectedWorkload>,
+    state: Res<BenchmarkState>,
+) {
+    if exit_events.read().last().is_some() {
+        if let Err(e) = LastSession::capture(*workload, &state).save() {
+            warn!("Failed to save last session: {}", e);
+        }
+    }
+}
+
+/// Save any collected results on exit, so an interrupted suite isn't lost
+fn save_results_on_exit(
+    mut exit_events: MessageReader<AppExit>,
+    config: Res<BenchmarkConfig>,
+    results: Res<BenchmarkResults>,
+) {
+    if exit_events.read().last().is_none() || !config.autosave_on_exit || !results.has_results() {
+        return;
+    }
+
+    match results.save_report() {
+        Ok(filename) => info!("Results auto-saved on exit to: {}", filename),
+        Err(e) => error!("Failed to auto-save results on exit: {}", e),
+    }
+}
+
 /// Handle keyboard input for benchmark control
 fn handle_input(
     keyboard: Res<ButtonInput<KeyCode>>,
@@ -66,43 +226,78 @@ fn handle_input(
     mut despawn_events: MessageWriter<DespawnAllRequest>,
     mut save_events: MessageWriter<SaveResultsRequest>,
     mut results: ResMut<BenchmarkResults>,
+    mut config: ResMut<BenchmarkConfig>,
+    mut drift: ResMut<DriftMonitor>,
+    mut rng: ResMut<FastRng>,
     mut exit: MessageWriter<AppExit>,
 ) {
-    // Escape to exit
+    // Escape cancels a running automated suite, otherwise exits
     if keyboard.just_pressed(KeyCode::Escape) {
-        exit.write(AppExit::Success);
+        if state.automated {
+            info!("Cancelling automated suite");
+            state.cancel_suite();
+            despawn_events.write(DespawnAllRequest);
+            state.reset(&config, *workload);
+            metrics.reset();
+            next_phase.set(BenchmarkPhase::Idle);
+            next_app_state.set(AppState::Menu);
+        } else {
+            exit.write(AppExit::Success);
+        }
         return;
     }
 
-    // Workload selection (1-6)
+    let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+
+    // Workload selection (0-9), or cycle with Tab / Shift+Tab
+    let mut selected = None;
     for key in [
+        KeyCode::Digit0,
         KeyCode::Digit1,
         KeyCode::Digit2,
         KeyCode::Digit3,
         KeyCode::Digit4,
         KeyCode::Digit5,
         KeyCode::Digit6,
+        KeyCode::Digit7,
+        KeyCode::Digit8,
+        KeyCode::Digit9,
+        KeyCode::Numpad0,
         KeyCode::Numpad1,
         KeyCode::Numpad2,
         KeyCode::Numpad3,
         KeyCode::Numpad4,
         KeyCode::Numpad5,
         KeyCode::Numpad6,
+        KeyCode::Numpad7,
+        KeyCode::Numpad8,
+        KeyCode::Numpad9,
     ] {
         if keyboard.just_pressed(key) {
-            if let Some(new_workload) = SelectedWorkload::from_key(key) {
-                // Stop current benchmark if running
-                if *app_state.get() == AppState::Running {
-                    despawn_events.write(DespawnAllRequest);
-                    next_phase.set(BenchmarkPhase::Idle);
-                    next_app_state.set(AppState::Menu);
-                }
+            selected = SelectedWorkload::from_key(key).or(selected);
+        }
+    }
+    if keyboard.just_pressed(KeyCode::Tab) {
+        selected = Some(if shift {
+            workload.previous()
+        } else {
+            workload.next()
+        });
+    }
 
-                *workload = new_workload;
-                state.reset_for_new_workload();
-                info!("Selected workload: {}", new_workload.name());
-            }
+    if let Some(new_workload) = selected {
+        // Picking a workload by hand abandons the automated suite
+        state.cancel_suite();
+        // Stop current benchmark if running
+        if *app_state.get() == AppState::Running {
+            despawn_events.write(DespawnAllRequest);
+            next_phase.set(BenchmarkPhase::Idle);
+            next_app_state.set(AppState::Menu);
         }
+
+        *workload = new_workload;
+        state.reset_for_new_workload(&config, *workload);
+        info!("Selected workload: {}", new_workload.name());
     }
 
     // Space to start/pause
@@ -110,7 +305,9 @@ fn handle_input(
         match app_state.get() {
             AppState::Menu | AppState::Paused => {
                 info!("Starting benchmark: {}", workload.name());
-                state.reset();
+                // Capture single-workload runs so they can be saved with S
+                results.ensure_report(config.default_target_ms, state.automated);
+                state.reset(&config, *workload);
                 metrics.reset();
                 spawn_events.write(SpawnEntitiesRequest {
                     count: state.entity_count,
@@ -134,7 +331,8 @@ fn handle_input(
     if keyboard.just_pressed(KeyCode::KeyR) {
         info!("Resetting benchmark");
         despawn_events.write(DespawnAllRequest);
-        state.reset();
+        state.cancel_suite();
+        state.reset(&config, *workload);
         metrics.reset();
         next_phase.set(BenchmarkPhase::Idle);
         next_app_state.set(AppState::Menu);
@@ -142,15 +340,24 @@ fn handle_input(
 
     // Enter to run full automated suite
     if keyboard.just_pressed(KeyCode::Enter) {
-        if *app_state.get() != AppState::Running {
+        if config.suite_workloads.is_empty() {
+            warn!("Suite workload list is empty; nothing to run");
+        } else if *app_state.get() != AppState::Running {
             info!("Starting automated benchmark suite");
-            results.start_new_report(TARGET_FRAME_TIME_MS);
+            results.start_new_report(config.default_target_ms);
+            drift.start();
+            info!(
+                "Empty-world baseline: {:.3}ms",
+                drift.baseline_ms.unwrap_or_default()
+            );
             state.automated = true;
             state.suite_index = 0;
-            *workload = *SelectedWorkload::all().first().unwrap();
+            state.suite_repeat = 0;
+            *workload = config.suite_workloads[0];
+            *rng = FastRng::for_workload(config.rng_seed, config.rng_backend, *workload);
 
             // Start first benchmark
-            state.reset();
+            state.reset(&config, *workload);
             metrics.reset();
             spawn_events.write(SpawnEntitiesRequest {
                 count: state.entity_count,
@@ -160,55 +367,201 @@ fn handle_input(
         }
     }
 
-    // Up/Down to manually adjust entity count
+    // T to cycle the target frame rate
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        config.cycle_default_target();
+        info!(
+            "Target frame time: {:.2}ms ({:.0} FPS)",
+            config.default_target_ms,
+            1000.0 / config.default_target_ms
+        );
+    }
+
+    // G to toggle raw / smoothed graph display
+    if keyboard.just_pressed(KeyCode::KeyG) {
+        config.graph_smoothing_enabled = !config.graph_smoothing_enabled;
+        info!(
+            "Graph display: {}",
+            if config.graph_smoothing_enabled {
+                "smoothed"
+            } else {
+                "raw"
+            }
+        );
+    }
+
+    // D to reset the config to its defaults (menu only, so never mid-run)
+    if *app_state.get() == AppState::Menu && keyboard.just_pressed(KeyCode::KeyD) {
+        let changes = config.reset_to_default();
+        if changes.is_empty() {
+            info!("Config already at defaults");
+        } else {
+            info!("Config reset to defaults: {}", changes.join(", "));
+        }
+    }
+
+    // S to save results
+    if keyboard.just_pressed(KeyCode::KeyS) {
+        save_events.write(SaveResultsRequest);
+    }
+}
+
+/// Up/Down to manually adjust entity count; ctrl scales by
+/// `MANUAL_STEP_FACTOR` instead of adding a fixed step. A running benchmark
+/// respawns at the new count once the old entities are gone.
+fn step_entity_count(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    app_state: Res<State<AppState>>,
+    mut next_phase: ResMut<NextState<BenchmarkPhase>>,
+    mut state: ResMut<BenchmarkState>,
+    mut pending: ResMut<PendingRespawn>,
+    mut despawn_events: MessageWriter<DespawnAllRequest>,
+) {
     let shift = keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight);
+    let ctrl = keyboard.pressed(KeyCode::ControlLeft) || keyboard.pressed(KeyCode::ControlRight);
     let step = if shift {
         crate::config::MANUAL_STEP_SIZE_LARGE
     } else {
         crate::config::MANUAL_STEP_SIZE
     };
 
-    if keyboard.just_pressed(KeyCode::ArrowUp) {
-        let new_count = (state.entity_count + step).min(crate::config::MAX_ENTITY_COUNT);
-        if *app_state.get() == AppState::Running {
-            despawn_events.write(DespawnAllRequest);
-            state.entity_count = new_count;
-            spawn_events.write(SpawnEntitiesRequest { count: new_count });
-            next_phase.set(BenchmarkPhase::WarmUp);
-        } else {
-            state.entity_count = new_count;
+    let new_count = match (keyboard.just_pressed(KeyCode::ArrowUp), ctrl) {
+        (true, true) => {
+            (state.entity_count as f64 * crate::config::MANUAL_STEP_FACTOR).round() as usize
+        }
+        (true, false) => state.entity_count.saturating_add(step),
+        (false, true) => {
+            (state.entity_count as f64 / crate::config::MANUAL_STEP_FACTOR).round() as usize
         }
-        info!("Entity count: {}", state.entity_count);
+        (false, false) => state.entity_count.saturating_sub(step),
     }
+    .clamp(
+        crate::config::MIN_ENTITY_COUNT,
+        crate::config::MAX_ENTITY_COUNT,
+    );
 
-    if keyboard.just_pressed(KeyCode::ArrowDown) {
-        let new_count = state.entity_count.saturating_sub(step).max(crate::config::MIN_ENTITY_COUNT);
-        if *app_state.get() == AppState::Running {
-            despawn_events.write(DespawnAllRequest);
-            state.entity_count = new_count;
-            spawn_events.write(SpawnEntitiesRequest { count: new_count });
-            next_phase.set(BenchmarkPhase::WarmUp);
-        } else {
-            state.entity_count = new_count;
-        }
-        info!("Entity count: {}", state.entity_count);
+    state.entity_count = new_count;
+    if *app_state.get() == AppState::Running {
+        despawn_events.write(DespawnAllRequest);
+        pending.request(new_count);
+        next_phase.set(BenchmarkPhase::WarmUp);
     }
+    info!("Entity count: {}", state.entity_count);
+}
 
-    // S to save results
-    if keyboard.just_pressed(KeyCode::KeyS) {
-        save_events.write(SaveResultsRequest);
+/// N to advance one phase in step mode
+fn request_phase_step(mut step: ResMut<StepRequested>) {
+    step.request();
+}
+
+/// Run condition: step mode is on
+fn step_mode_enabled(config: Res<BenchmarkConfig>) -> bool {
+    config.step_mode
+}
+
+/// Run condition: settings that shape the spawned entities may change.
+///
+/// Locked while running or paused so live entities always match the config.
+fn spawn_settings_unlocked(app_state: Res<State<AppState>>) -> bool {
+    !matches!(app_state.get(), AppState::Running | AppState::Paused)
+}
+
+/// Run condition: the selected workload uses the churn rate
+fn selected_uses_churn_rate(workload: Res<SelectedWorkload>) -> bool {
+    workload.uses_churn_rate()
+}
+
+/// [ and ] to adjust the churn rate (applied on the next spawn setup)
+fn change_churn_rate(keyboard: Res<ButtonInput<KeyCode>>, mut config: ResMut<BenchmarkConfig>) {
+    config.adjust_churn_rate(keyboard.just_pressed(KeyCode::BracketRight));
+    info!("Churn rate: {:.1}% per frame", config.churn_rate * 100.0);
+}
+
+/// C to cycle the spawn chunk size (applied on the next spawn)
+fn cycle_spawn_chunk_size(mut config: ResMut<BenchmarkConfig>) {
+    config.cycle_spawn_chunk_size();
+    info!("Spawn chunk size: {}", config.spawn_chunk_size);
+}
+
+/// H to cycle the recursive despawn tree depth
+fn cycle_hierarchy_depth(mut config: ResMut<BenchmarkConfig>) {
+    config.cycle_hierarchy_depth();
+    info!(
+        "Hierarchy depth: {} ({} entities per tree)",
+        config.hierarchy_depth,
+        config.hierarchy_tree_size()
+    );
+}
+
+/// W to widen the Multi-Component Read query by one component (1-6)
+fn cycle_multi_component_count(mut config: ResMut<BenchmarkConfig>) {
+    config.cycle_multi_component_count();
+    log_variant(&config, SelectedWorkload::MultiComponentRead);
+}
+
+/// U to cycle the Component Add/Remove toggle fraction
+fn cycle_toggle_fraction(mut config: ResMut<BenchmarkConfig>) {
+    config.cycle_toggle_fraction();
+    info!("Toggle fraction: {}", config.toggle_fraction);
+}
+
+/// Y to cycle the Component Add/Remove toggle period
+fn cycle_toggle_period(mut config: ResMut<BenchmarkConfig>) {
+    config.cycle_toggle_period();
+    info!("Toggle period: {} frames", config.toggle_period);
+}
+
+/// Z to switch the Component Add/Remove per-group cap off or on
+fn toggle_toggle_max(mut config: ResMut<BenchmarkConfig>) {
+    config.toggle_toggle_max();
+    match config.toggle_max {
+        Some(max) => info!("Toggle cap: {} entities per group", max),
+        None => info!("Toggle cap: none"),
     }
 }
 
+/// Flip a boolean sub-variant of the selected workload and log the new one.
+///
+/// Registered once per workload with that workload's key, e.g. B switches
+/// Bitflag Branching between checks and no checks.
+fn toggle_variant(mut config: ResMut<BenchmarkConfig>, workload: Res<SelectedWorkload>) {
+    let flag = match *workload {
+        SelectedWorkload::BitflagBranching => &mut config.bitflag_checks,
+        SelectedWorkload::ParallelPrepSpawn => &mut config.parallel_prep,
+        SelectedWorkload::LocalVsRes => &mut config.local_cache,
+        SelectedWorkload::BulkDespawn => &mut config.bulk_despawn,
+        SelectedWorkload::DisjointParallel => &mut config.disjoint_chained,
+        SelectedWorkload::IterStyle => &mut config.iter_for_each,
+        SelectedWorkload::EntityMapLookup => &mut config.entity_map_direct,
+        SelectedWorkload::RunConditionOverhead => &mut config.run_conditions_pass,
+        SelectedWorkload::PositionIteration => &mut config.sparse_storage,
+        _ => return,
+    };
+    *flag = !*flag;
+    log_variant(&config, *workload);
+}
+
+/// Log the sub-variant `workload` now runs in
+fn log_variant(config: &BenchmarkConfig, workload: SelectedWorkload) {
+    info!(
+        "{}: {}",
+        workload.name(),
+        config.variant_name(workload).unwrap_or_default()
+    );
+}
+
 /// Update frame metrics each frame
 fn update_metrics(
     time: Res<Time>,
+    config: Res<BenchmarkConfig>,
+    phase: Res<State<BenchmarkPhase>>,
     mut metrics: ResMut<FrameMetrics>,
     _state: Res<BenchmarkState>,
     query: Query<&BenchmarkEntity>,
 ) {
     let entity_count = query.iter().count();
-    metrics.record_frame(time.delta_secs_f64(), entity_count);
+    let secs = metrics.measured_secs(&time, config.timing);
+    metrics.record_frame(secs, entity_count, *phase.get());
 }
 
 /// Handle benchmark completion - advance to next workload in automated mode
@@ -218,9 +571,13 @@ fn handle_benchmark_complete(
     mut workload: ResMut<SelectedWorkload>,
     mut next_app_state: ResMut<NextState<AppState>>,
     mut next_phase: ResMut<NextState<BenchmarkPhase>>,
-    mut spawn_events: MessageWriter<SpawnEntitiesRequest>,
+    mut pending: ResMut<PendingRespawn>,
     mut despawn_events: MessageWriter<DespawnAllRequest>,
     mut metrics: ResMut<FrameMetrics>,
+    mut results: ResMut<BenchmarkResults>,
+    mut drift: ResMut<DriftMonitor>,
+    mut rng: ResMut<FastRng>,
+    config: Res<BenchmarkConfig>,
 ) {
     for event in events.read() {
         info!(
@@ -231,27 +588,83 @@ fn handle_benchmark_complete(
         );
 
         if state.automated {
+            // Re-measure the empty-world baseline to catch throttling
+            let was_detected = drift.drift_detected;
+            if let Some(percent) = drift.check(config.thermal_drift_percent) {
+                if drift.drift_detected && !was_detected {
+                    warn!(
+                        "Thermal drift detected: empty-world baseline rose {:.1}% (threshold {:.1}%); \
+                         later workloads may not be comparable with earlier ones",
+                        percent, config.thermal_drift_percent
+                    );
+                }
+            }
+            results.record_thermal_drift(drift.drift_detected, drift.max_drift_percent);
+
+            // Re-run a noisy result instead of trusting it, while retries remain
+            let cv = results
+                .current_workload_result
+                .as_ref()
+                .map_or(0.0, |result| result.stability_cv);
+            if cv > config.retry_cv_threshold && state.retries < config.max_retries {
+                let retries = state.retries + 1;
+                warn!(
+                    "{}: stability CV {:.3} above {:.3}, retrying ({}/{})",
+                    workload.name(),
+                    cv,
+                    config.retry_cv_threshold,
+                    retries,
+                    config.max_retries
+                );
+                results.discard_last_result();
+
+                *rng = FastRng::for_workload(config.rng_seed, config.rng_backend, *workload);
+                despawn_events.write(DespawnAllRequest);
+                state.reset_for_new_workload(&config, *workload);
+                state.retries = retries;
+                metrics.reset();
+
+                pending.request(state.entity_count);
+                next_phase.set(next_workload_phase(&config));
+                continue;
+            }
+
             // Advance to next workload
             state.suite_index += 1;
-            let workloads = SelectedWorkload::all();
+            let workloads = &config.suite_workloads;
+
+            // Wrap around for the next repeat of the suite
+            if state.suite_index >= workloads.len() && state.suite_repeat + 1 < config.repeats {
+                state.suite_index = 0;
+                state.suite_repeat += 1;
+                info!(
+                    "Starting suite repeat {}/{}",
+                    state.suite_repeat + 1,
+                    config.repeats
+                );
+            }
 
             if state.suite_index < workloads.len() {
                 // More workloads to test
                 *workload = workloads[state.suite_index];
                 info!("Advancing to next workload: {}", workload.name());
 
+                // Same random data for a workload regardless of suite order
+                *rng = FastRng::for_workload(config.rng_seed, config.rng_backend, *workload);
+
                 despawn_events.write(DespawnAllRequest);
-                state.reset_for_new_workload();
+                state.reset_for_new_workload(&config, *workload);
                 metrics.reset();
 
-                // Small delay, then start next
-                spawn_events.write(SpawnEntitiesRequest {
-                    count: state.entity_count,
-                });
-                next_phase.set(BenchmarkPhase::WarmUp);
+                // Spawn once the previous workload's entities are gone and
+                // the cooldown has passed
+                pending.request(state.entity_count);
+                next_phase.set(next_workload_phase(&config));
             } else {
                 // Suite complete
                 info!("Automated suite complete!");
+                results.finalize_report();
+                info!("Suite summary:\n{}", results.summary_table_string());
                 state.automated = false;
                 next_app_state.set(AppState::Results);
                 next_phase.set(BenchmarkPhase::Complete);
@@ -260,6 +673,16 @@ fn handle_benchmark_complete(
     }
 }
 
+/// Phase the suite enters after despawning a finished workload: the
+/// cooldown if enabled, otherwise straight into the next warm-up
+fn next_workload_phase(config: &BenchmarkConfig) -> BenchmarkPhase {
+    if config.cooldown_frames > 0 {
+        BenchmarkPhase::Cooldown
+    } else {
+        BenchmarkPhase::WarmUp
+    }
+}
+
 /// Handle save results request
 fn handle_save_request(
     mut events: MessageReader<SaveResultsRequest>,
@@ -276,3 +699,78 @@ fn handle_save_request(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::state::app::StatesPlugin;
+
+    use super::*;
+
+    /// `handle_input` and `handle_benchmark_complete` on top of the runner,
+    /// without the UI or a window
+    fn input_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .add_plugins(BenchmarkRunnerPlugin)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<DriftMonitor>()
+            .init_resource::<FastRng>()
+            .add_message::<SaveResultsRequest>()
+            .add_systems(
+                Update,
+                (handle_input, handle_benchmark_complete.after(handle_input)),
+            );
+        app.update();
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(key);
+        app.update();
+        // No InputPlugin to clear just_pressed between frames
+        let mut keyboard = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keyboard.release(key);
+        keyboard.clear();
+    }
+
+    #[test]
+    fn selecting_a_workload_cancels_the_suite() {
+        let mut app = input_app();
+        {
+            let mut state = app.world_mut().resource_mut::<BenchmarkState>();
+            state.automated = true;
+            state.suite_index = 2;
+        }
+
+        press(&mut app, KeyCode::Digit2);
+        let selected = *app.world().resource::<SelectedWorkload>();
+        assert_eq!(Some(selected), SelectedWorkload::from_key(KeyCode::Digit2));
+        let state = app.world().resource::<BenchmarkState>();
+        assert!(!state.automated);
+        assert_eq!(state.suite_index, 0);
+
+        // A completion arriving after the cancel must not advance the suite
+        app.world_mut().write_message(BenchmarkComplete {
+            workload: selected,
+            breakdown_point: 1_000,
+            throughput: 1_000.0,
+        });
+        app.update();
+        assert_eq!(*app.world().resource::<SelectedWorkload>(), selected);
+        let state = app.world().resource::<BenchmarkState>();
+        assert!(!state.automated);
+        assert_eq!(state.suite_index, 0);
+    }
+
+    #[test]
+    fn escape_cancels_the_suite_without_exiting() {
+        let mut app = input_app();
+        app.world_mut().resource_mut::<BenchmarkState>().automated = true;
+
+        press(&mut app, KeyCode::Escape);
+        assert!(!app.world().resource::<BenchmarkState>().automated);
+        assert!(app.should_exit().is_none());
+    }
+}
@@ -1,6 +1,13 @@
 //! Application state management for the benchmark suite.
 
+use bevy::platform::time::Instant;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::benchmark::results::write_atomic;
+use crate::config::{results_dir, BenchmarkConfig};
 
 /// Main application states
 #[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, States)]
@@ -30,6 +37,9 @@ pub enum BenchmarkPhase {
     Adjusting,
     /// Benchmark complete
     Complete,
+    /// Idle frames between suite workloads so despawn work from the last
+    /// workload settles before the next warm-up
+    Cooldown,
 }
 
 /// Currently selected workload type
@@ -42,6 +52,35 @@ pub enum SelectedWorkload {
     SpawnDespawn,
     ComponentAddRemove,
     FragmentedArchetypes,
+    OrFilter,
+    AddedDetection,
+    DropHeavy,
+    ResourceContention,
+    ResourceParallel,
+    VariablePayload,
+    IncrementalInsert,
+    PositionIteration,
+    EntityIdIteration,
+    RandomAccess,
+    SpriteRender,
+    ManySystems,
+    CombinedQuery,
+    QueryTransmute,
+    ChunkedSpawn,
+    BitflagBranching,
+    ParallelPrepSpawn,
+    RecursiveDespawn,
+    ExclusiveWorld,
+    LocalVsRes,
+    EmptyBaseline,
+    PairwiseCombinations,
+    BulkDespawn,
+    DisjointParallel,
+    IterStyle,
+    ResourceChurn,
+    DefragRecovery,
+    EntityMapLookup,
+    RunConditionOverhead,
 }
 
 impl SelectedWorkload {
@@ -53,6 +92,35 @@ impl SelectedWorkload {
             Self::SpawnDespawn => "Spawn/Despawn Churn",
             Self::ComponentAddRemove => "Component Add/Remove",
             Self::FragmentedArchetypes => "Fragmented Archetypes",
+            Self::OrFilter => "Or Filter Query",
+            Self::AddedDetection => "Added Detection",
+            Self::DropHeavy => "Drop-Heavy Churn",
+            Self::ResourceContention => "Resource Contention",
+            Self::ResourceParallel => "Resource Parallel",
+            Self::VariablePayload => "Variable Payload",
+            Self::IncrementalInsert => "Incremental Insert",
+            Self::PositionIteration => "Position Iteration",
+            Self::EntityIdIteration => "Entity Id Iteration",
+            Self::RandomAccess => "Random Access",
+            Self::SpriteRender => "Sprite Render",
+            Self::ManySystems => "Many Systems",
+            Self::CombinedQuery => "Combined Query",
+            Self::QueryTransmute => "Query Transmute",
+            Self::ChunkedSpawn => "Chunked Spawn",
+            Self::BitflagBranching => "Bitflag Branching",
+            Self::ParallelPrepSpawn => "Parallel Prep Spawn",
+            Self::RecursiveDespawn => "Recursive Despawn",
+            Self::ExclusiveWorld => "Exclusive World Churn",
+            Self::LocalVsRes => "Local vs Res",
+            Self::EmptyBaseline => "Empty Baseline",
+            Self::PairwiseCombinations => "Pairwise Combinations",
+            Self::BulkDespawn => "Bulk Despawn",
+            Self::DisjointParallel => "Disjoint Parallel",
+            Self::IterStyle => "Iter Style",
+            Self::ResourceChurn => "Resource Churn",
+            Self::DefragRecovery => "Defrag Recovery",
+            Self::EntityMapLookup => "Entity Map Lookup",
+            Self::RunConditionOverhead => "Run Condition Overhead",
         }
     }
 
@@ -64,20 +132,178 @@ impl SelectedWorkload {
             Self::SpawnDespawn => "Spawn and despawn entities each frame",
             Self::ComponentAddRemove => "Add/remove components on existing entities",
             Self::FragmentedArchetypes => "Entities spread across many archetypes",
+            Self::OrFilter => "Query with Or<(With<A>, With<B>)> filter",
+            Self::AddedDetection => "Detect newly spawned entities with Added<T>",
+            Self::DropHeavy => "Spawn/despawn entities whose components own heap data",
+            Self::ResourceContention => "Systems serialized on one shared ResMut",
+            Self::ResourceParallel => "Same systems on distinct resources, run in parallel",
+            Self::VariablePayload => "Read a runtime-sized boxed payload per entity",
+            Self::IncrementalInsert => "Spawn churn via spawn_empty().insert(a).insert(b)",
+            Self::PositionIteration => "Component-only iteration over Position (O for sparse set)",
+            Self::EntityIdIteration => "Iterate (Entity, &Position) and read the id",
+            Self::RandomAccess => "Query::get in shuffled entity order",
+            Self::SpriteRender => "Moving sprites: ECS plus render extraction and drawing",
+            Self::ManySystems => "Hundreds of trivial systems: scheduler overhead",
+            Self::CombinedQuery => "Plain-query baseline for Query Transmute",
+            Self::QueryTransmute => "Same reads through transmute_lens and join",
+            Self::ChunkedSpawn => "Respawn everything each frame via spawn_batch chunks",
+            Self::BitflagBranching => "EntityVariant has() branches vs. no checks (B to toggle)",
+            Self::ParallelPrepSpawn => {
+                "Bundle data built on the task pool, then one spawn_batch (P to toggle)"
+            }
+            Self::RecursiveDespawn => "Despawn whole parent/child trees each frame (H for depth)",
+            Self::ExclusiveWorld => "Spawn/Despawn churn applied directly through &mut World",
+            Self::LocalVsRes => {
+                "Derived per-frame value cached in Local<T> vs recomputed (L to toggle)"
+            }
+            Self::EmptyBaseline => {
+                "Spawns nothing and runs no workload systems: pure engine overhead"
+            }
+            Self::PairwiseCombinations => {
+                "O(n²) iter_combinations over Position pairs (count capped)"
+            }
+            Self::BulkDespawn => {
+                "Full teardown every frame: clear_entities vs per-entity (X to toggle)"
+            }
+            Self::DisjointParallel => {
+                "Position read + Counter write systems (K: parallel / chained)"
+            }
+            Self::IterStyle => {
+                "Position/Velocity sum as a for loop or iter().for_each (F to toggle)"
+            }
+            Self::ResourceChurn => {
+                "Insert and remove a marker resource via Commands each frame: fixed cost"
+            }
+            Self::DefragRecovery => {
+                "Up to 256 archetypes stripped back to one: iteration before vs after"
+            }
+            Self::EntityMapLookup => {
+                "EntityHashMap<usize> get per entity vs reading the component (M to toggle)"
+            }
+            Self::RunConditionOverhead => {
+                "Hundreds of run_if-guarded systems, most skipped: condition cost"
+            }
+        }
+    }
+
+    /// Number key that selects this workload, if it has one
+    pub fn key_hint(&self) -> Option<&'static str> {
+        match self {
+            Self::SimpleIteration => Some("1"),
+            Self::MultiComponentRead => Some("2"),
+            Self::PositionVelocity => Some("3"),
+            Self::SpawnDespawn => Some("4"),
+            Self::ComponentAddRemove => Some("5"),
+            Self::FragmentedArchetypes => Some("6"),
+            Self::OrFilter => Some("7"),
+            Self::AddedDetection => Some("8"),
+            Self::DropHeavy => Some("9"),
+            Self::ResourceContention => Some("0"),
+            // Only reachable by cycling with Tab
+            _ => None,
         }
     }
 
-    pub fn key_hint(&self) -> &'static str {
+    /// Whether this workload measures structural changes (spawn/despawn, add/remove)
+    pub fn is_structural(&self) -> bool {
+        matches!(
+            self,
+            Self::SpawnDespawn
+                | Self::ComponentAddRemove
+                | Self::DropHeavy
+                | Self::IncrementalInsert
+                | Self::ChunkedSpawn
+                | Self::ParallelPrepSpawn
+                | Self::RecursiveDespawn
+                | Self::ExclusiveWorld
+                | Self::BulkDespawn
+        )
+    }
+
+    /// Highest entity count the breakdown search will try for this workload
+    pub fn max_entity_count(&self) -> usize {
         match self {
-            Self::SimpleIteration => "1",
-            Self::MultiComponentRead => "2",
-            Self::PositionVelocity => "3",
-            Self::SpawnDespawn => "4",
-            Self::ComponentAddRemove => "5",
-            Self::FragmentedArchetypes => "6",
+            // n entities means n(n-1)/2 pairs per frame
+            Self::PairwiseCombinations => crate::config::PAIRWISE_MAX_ENTITY_COUNT,
+            _ => crate::config::MAX_ENTITY_COUNT,
         }
     }
 
+    /// Entity count the breakdown search starts from when none is
+    /// configured, for workloads that shouldn't start at the usual count
+    pub fn default_initial_count(&self) -> Option<usize> {
+        match self {
+            // Starting at the cap would measure ~50M pairs on the first frame
+            Self::PairwiseCombinations => Some(crate::config::PAIRWISE_INITIAL_ENTITY_COUNT),
+            _ => None,
+        }
+    }
+
+    /// Whether the spawn systems create exactly the requested entity count
+    /// (Empty Baseline and Resource Churn spawn nothing, Recursive Despawn
+    /// rounds to whole trees, Bulk Despawn spawns into its own scratch World)
+    pub fn spawns_requested_count(&self) -> bool {
+        !matches!(
+            self,
+            Self::EmptyBaseline | Self::ResourceChurn | Self::RecursiveDespawn | Self::BulkDespawn
+        )
+    }
+
+    /// Whether the workload re-reads the same component data every frame, so
+    /// the cache-warming passes at the end of warm-up apply to it
+    pub fn warms_cache(&self) -> bool {
+        !self.is_structural() && !self.is_fixed_cost()
+    }
+
+    /// Whether the workload's reads honour `BenchmarkConfig::black_box_reads`
+    pub fn uses_black_box_toggle(&self) -> bool {
+        matches!(
+            self,
+            Self::SimpleIteration
+                | Self::MultiComponentRead
+                | Self::PositionIteration
+                | Self::EntityIdIteration
+        )
+    }
+
+    /// Whether the workload's cost doesn't scale with the entity count: a
+    /// single measurement is the result, not a breakdown search
+    pub fn is_fixed_cost(&self) -> bool {
+        matches!(
+            self,
+            Self::EmptyBaseline
+                | Self::ResourceChurn
+                | Self::ManySystems
+                | Self::RunConditionOverhead
+        )
+    }
+
+    /// Automated suite order: `EmptyBaseline` first so later results can be
+    /// reported net of it, then the rest of `all()`
+    pub fn suite_default() -> Vec<SelectedWorkload> {
+        std::iter::once(Self::EmptyBaseline)
+            .chain(
+                Self::all()
+                    .iter()
+                    .copied()
+                    .filter(|w| *w != Self::EmptyBaseline),
+            )
+            .collect()
+    }
+
+    /// Whether this workload's per-frame churn is driven by `BenchmarkConfig::churn_rate`
+    pub fn uses_churn_rate(&self) -> bool {
+        matches!(
+            self,
+            Self::SpawnDespawn
+                | Self::AddedDetection
+                | Self::DropHeavy
+                | Self::IncrementalInsert
+                | Self::RecursiveDespawn
+                | Self::ExclusiveWorld
+        )
+    }
+
     pub fn all() -> &'static [SelectedWorkload] {
         &[
             Self::SimpleIteration,
@@ -86,9 +312,85 @@ impl SelectedWorkload {
             Self::SpawnDespawn,
             Self::ComponentAddRemove,
             Self::FragmentedArchetypes,
+            Self::OrFilter,
+            Self::AddedDetection,
+            Self::DropHeavy,
+            Self::ResourceContention,
+            Self::ResourceParallel,
+            Self::VariablePayload,
+            Self::IncrementalInsert,
+            Self::PositionIteration,
+            Self::EntityIdIteration,
+            Self::RandomAccess,
+            Self::SpriteRender,
+            Self::ManySystems,
+            Self::CombinedQuery,
+            Self::QueryTransmute,
+            Self::ChunkedSpawn,
+            Self::BitflagBranching,
+            Self::ParallelPrepSpawn,
+            Self::RecursiveDespawn,
+            Self::ExclusiveWorld,
+            Self::LocalVsRes,
+            Self::EmptyBaseline,
+            Self::PairwiseCombinations,
+            Self::BulkDespawn,
+            Self::DisjointParallel,
+            Self::IterStyle,
+            Self::ResourceChurn,
+            Self::DefragRecovery,
+            Self::EntityMapLookup,
+            Self::RunConditionOverhead,
         ]
     }
 
+    /// Position of this workload in `all()` (stable regardless of suite order)
+    pub fn index(&self) -> usize {
+        Self::all().iter().position(|w| w == self).unwrap_or(0)
+    }
+
+    /// The workload after this one in `all()`, wrapping around
+    pub fn next(&self) -> Self {
+        let all = Self::all();
+        let index = all.iter().position(|w| w == self).unwrap_or(0);
+        all[(index + 1) % all.len()]
+    }
+
+    /// The workload before this one in `all()`, wrapping around
+    pub fn previous(&self) -> Self {
+        let all = Self::all();
+        let index = all.iter().position(|w| w == self).unwrap_or(0);
+        all[(index + all.len() - 1) % all.len()]
+    }
+
+    /// Look up a workload by its display name (as stored in reports and sessions)
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all().iter().copied().find(|w| w.name() == name)
+    }
+
+    /// Parse a comma-separated workload list such as `2,5` or
+    /// `2,Random Access`. Entries are key hints (digits) or display names
+    /// (case-insensitive).
+    pub fn parse_list(list: &str) -> Result<Vec<Self>, String> {
+        let workloads = list
+            .split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                Self::all()
+                    .iter()
+                    .copied()
+                    .find(|w| w.key_hint() == Some(entry) || w.name().eq_ignore_ascii_case(entry))
+                    .ok_or_else(|| format!("Unknown workload: {}", entry))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if workloads.is_empty() {
+            return Err("Workload list is empty".to_string());
+        }
+        Ok(workloads)
+    }
+
     pub fn from_key(key: KeyCode) -> Option<Self> {
         match key {
             KeyCode::Digit1 | KeyCode::Numpad1 => Some(Self::SimpleIteration),
@@ -97,6 +399,10 @@ impl SelectedWorkload {
             KeyCode::Digit4 | KeyCode::Numpad4 => Some(Self::SpawnDespawn),
             KeyCode::Digit5 | KeyCode::Numpad5 => Some(Self::ComponentAddRemove),
             KeyCode::Digit6 | KeyCode::Numpad6 => Some(Self::FragmentedArchetypes),
+            KeyCode::Digit7 | KeyCode::Numpad7 => Some(Self::OrFilter),
+            KeyCode::Digit8 | KeyCode::Numpad8 => Some(Self::AddedDetection),
+            KeyCode::Digit9 | KeyCode::Numpad9 => Some(Self::DropHeavy),
+            KeyCode::Digit0 | KeyCode::Numpad0 => Some(Self::ResourceContention),
             _ => None,
         }
     }
@@ -117,6 +423,27 @@ pub struct BenchmarkState {
     pub automated: bool,
     /// Index of current workload in automated suite
     pub suite_index: usize,
+    /// Current repeat of the automated suite (0-based)
+    pub suite_repeat: usize,
+    /// Entity count each search starts from (restored from the last session)
+    pub start_entity_count: usize,
+    /// `BenchmarkEntity` count actually present in the last validated iteration
+    pub spawned_count: usize,
+    /// Whether any iteration of the current search spawned the wrong count
+    pub spawn_mismatch: bool,
+    /// When the current workload first entered warm-up (None until it does)
+    pub workload_started_at: Option<Instant>,
+    /// Warm-up frames spent across every iteration of the current search
+    pub warmup_frames_total: usize,
+    /// Tasks the last instrumented `par_iter_mut` frame was split into
+    pub par_iter_tasks: Option<usize>,
+    /// Cache-warming passes left before sampling; None until warm-up settles
+    pub cache_warm_remaining: Option<usize>,
+    /// Times the automated suite has re-run the current workload for a noisy result
+    pub retries: usize,
+    /// Measured count with the highest throughput in the current search, and
+    /// that throughput (entities/sec)
+    pub knee: Option<(usize, f64)>,
 }
 
 impl Default for BenchmarkState {
@@ -128,22 +455,113 @@ impl Default for BenchmarkState {
             frame_counter: 0,
             automated: false,
             suite_index: 0,
+            suite_repeat: 0,
+            start_entity_count: crate::config::INITIAL_ENTITY_COUNT,
+            spawned_count: 0,
+            spawn_mismatch: false,
+            workload_started_at: None,
+            warmup_frames_total: 0,
+            par_iter_tasks: None,
+            cache_warm_remaining: None,
+            retries: 0,
+            knee: None,
         }
     }
 }
 
 impl BenchmarkState {
-    pub fn reset(&mut self) {
-        self.entity_count = crate::config::INITIAL_ENTITY_COUNT;
+    /// Count a search for `workload` starts from: its configured initial
+    /// count, else the workload's own default, else `start_entity_count`,
+    /// within the workload's limits
+    pub fn initial_count(&self, config: &BenchmarkConfig, workload: SelectedWorkload) -> usize {
+        config
+            .initial_count(workload)
+            .or(workload.default_initial_count())
+            .unwrap_or(self.start_entity_count)
+            .clamp(crate::config::MIN_ENTITY_COUNT, workload.max_entity_count())
+    }
+
+    pub fn reset(&mut self, config: &BenchmarkConfig, workload: SelectedWorkload) {
+        self.entity_count = self.initial_count(config, workload);
         self.search_low = crate::config::MIN_ENTITY_COUNT;
         self.search_high = crate::config::MAX_ENTITY_COUNT;
         self.frame_counter = 0;
+        self.spawned_count = 0;
+        self.spawn_mismatch = false;
+        self.workload_started_at = None;
+        self.warmup_frames_total = 0;
+        self.par_iter_tasks = None;
+        self.cache_warm_remaining = None;
+        self.retries = 0;
+        self.knee = None;
+    }
+
+    /// Abandon an in-progress automated suite
+    pub fn cancel_suite(&mut self) {
+        self.automated = false;
+        self.suite_index = 0;
+        self.suite_repeat = 0;
     }
 
-    pub fn reset_for_new_workload(&mut self) {
-        self.entity_count = crate::config::INITIAL_ENTITY_COUNT;
+    pub fn reset_for_new_workload(&mut self, config: &BenchmarkConfig, workload: SelectedWorkload) {
+        self.entity_count = self.initial_count(config, workload);
         self.search_low = crate::config::MIN_ENTITY_COUNT;
         self.search_high = crate::config::MAX_ENTITY_COUNT;
         self.frame_counter = 0;
+        self.spawned_count = 0;
+        self.spawn_mismatch = false;
+        self.workload_started_at = None;
+        self.warmup_frames_total = 0;
+        self.par_iter_tasks = None;
+        self.cache_warm_remaining = None;
+        self.retries = 0;
+        self.knee = None;
+    }
+}
+
+/// Workload and entity count remembered between launches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LastSession {
+    pub workload: String,
+    pub entity_count: usize,
+}
+
+impl LastSession {
+    fn path() -> PathBuf {
+        results_dir().join("last_session.json")
+    }
+
+    pub fn capture(workload: SelectedWorkload, state: &BenchmarkState) -> Self {
+        Self {
+            workload: workload.name().to_string(),
+            entity_count: state.entity_count,
+        }
+    }
+
+    /// Load the previous session, if one was saved and is still readable
+    pub fn load() -> Option<Self> {
+        let json = fs::read_to_string(Self::path()).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        fs::create_dir_all(results_dir())
+            .map_err(|e| format!("Failed to create directory: {}", e))?;
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| format!("Failed to serialize: {}", e))?;
+        write_atomic(&Self::path(), &json)
+    }
+
+    /// Apply to the live resources; unknown workload names are ignored
+    pub fn apply(&self, workload: &mut SelectedWorkload, state: &mut BenchmarkState) {
+        if let Some(restored) = SelectedWorkload::from_name(&self.workload) {
+            *workload = restored;
+        }
+        let count = self.entity_count.clamp(
+            crate::config::MIN_ENTITY_COUNT,
+            crate::config::MAX_ENTITY_COUNT,
+        );
+        state.entity_count = count;
+        state.start_entity_count = count;
     }
 }
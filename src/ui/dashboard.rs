@@ -3,9 +3,14 @@
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
 
-use crate::config::{colors, sizes, TARGET_FRAME_TIME_MS};
-use crate::metrics::{format_count, format_throughput, FrameMetrics};
+use crate::benchmark::results::{BenchmarkResults, ReportComparison};
+use crate::config::{colors, sizes, BenchmarkConfig, TARGET_FRAME_TIME_MS};
+use crate::metrics::{
+    format_bytes, format_count, format_duration, format_throughput, FrameMetrics, ParallelismStats,
+    WorldStats,
+};
 use crate::state::{BenchmarkPhase, BenchmarkState, SelectedWorkload};
+use crate::ui::graph::target_line_bottom;
 use crate::ui::styles::*;
 
 // =============================================================================
@@ -24,6 +29,49 @@ pub struct FrameTimeText;
 #[derive(Component)]
 pub struct ThroughputText;
 
+#[derive(Component)]
+pub struct TargetText;
+
+#[derive(Component)]
+pub struct P95Text;
+
+#[derive(Component)]
+pub struct WorstSpikeText;
+
+#[derive(Component)]
+pub struct JitterText;
+
+#[derive(Component)]
+pub struct FpsText;
+
+#[derive(Component)]
+pub struct MinFrameTimeText;
+
+#[derive(Component)]
+pub struct AvgFrameTimeText;
+
+#[derive(Component)]
+pub struct MaxFrameTimeText;
+
+#[derive(Component)]
+pub struct StructuralOpsSection;
+
+#[derive(Component)]
+pub struct VsyncWarningText;
+
+#[derive(Component)]
+pub struct WorldStatsText;
+
+/// Marker for the parallel iteration threads readout
+#[derive(Component)]
+pub struct ThreadsUsedText;
+
+#[derive(Component)]
+pub struct StructuralOpsText;
+
+#[derive(Component)]
+pub struct ChurnRateText;
+
 #[derive(Component)]
 pub struct PhaseText;
 
@@ -41,17 +89,41 @@ pub struct GraphBar {
 #[derive(Component)]
 pub struct TargetLine;
 
+#[derive(Component)]
+pub struct ThroughputGraphContainer;
+
+#[derive(Component)]
+pub struct ThroughputBar {
+    pub index: usize,
+}
+
+#[derive(Component)]
+pub struct ThroughputAxisLabel;
+
+#[derive(Component)]
+pub struct GraphAxisLabel;
+
+#[derive(Component)]
+pub struct GraphTooltip;
+
 #[derive(Component)]
 pub struct ControlsHint;
 
 #[derive(Component)]
 pub struct WorkloadDescriptionText;
 
+#[derive(Component)]
+pub struct ResultsTable;
+
+/// Marker for the `--compare` report diff table
+#[derive(Component)]
+pub struct ComparisonTable;
+
 // =============================================================================
 // UI Setup
 // =============================================================================
 
-pub fn setup_ui(mut commands: Commands) {
+pub fn setup_ui(mut commands: Commands, config: Res<BenchmarkConfig>) {
     // Root container - full screen
     commands
         .spawn((
@@ -69,7 +141,23 @@ pub fn setup_ui(mut commands: Commands) {
             spawn_sidebar(parent);
 
             // Main content area (graph)
-            spawn_main_content(parent);
+            spawn_main_content(parent, config.graph_bar_count);
+
+            // Hover tooltip for graph bars (positioned at the cursor)
+            parent.spawn((
+                GraphTooltip,
+                Text::new(""),
+                small_text_font(),
+                TextColor(colors::TEXT_PRIMARY),
+                Node {
+                    position_type: PositionType::Absolute,
+                    display: Display::None,
+                    padding: UiRect::all(Val::Px(6.0)),
+                    ..default()
+                },
+                BackgroundColor(colors::PANEL_BG),
+                GlobalZIndex(10),
+            ));
         });
 }
 
@@ -99,6 +187,19 @@ fn spawn_sidebar(parent: &mut ChildSpawnerCommands) {
                 TextColor(colors::TEXT_SECONDARY),
             ));
 
+            // Vsync warning - hidden unless detect_vsync flags it
+            sidebar.spawn((
+                VsyncWarningText,
+                Text::new("Vsync suspected: frame times may be capped"),
+                small_text_font(),
+                TextColor(colors::DANGER),
+                Node {
+                    display: Display::None,
+                    margin: UiRect::top(Val::Px(8.0)),
+                    ..default()
+                },
+            ));
+
             // Spacing
             sidebar.spawn(section_spacing());
 
@@ -176,20 +277,102 @@ fn spawn_metrics_section(parent: &mut ChildSpawnerCommands) {
         TextColor(colors::TEXT_SECONDARY),
     ));
 
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Baseline,
+            column_gap: Val::Px(12.0),
+            margin: UiRect::bottom(Val::Px(8.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                FrameTimeText,
+                Text::new("0.00ms"),
+                heading_text_font(),
+                TextColor(colors::SUCCESS),
+            ));
+
+            row.spawn((
+                FpsText,
+                Text::new("0 FPS"),
+                large_metric_font(),
+                TextColor(colors::SUCCESS),
+            ));
+        });
+
+    // Min / avg / max over the rolling frame history
+    parent
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(12.0),
+            margin: UiRect::bottom(Val::Px(4.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            row.spawn((
+                MinFrameTimeText,
+                Text::new("min: --"),
+                small_text_font(),
+                TextColor(colors::TEXT_SECONDARY),
+            ));
+            row.spawn((
+                AvgFrameTimeText,
+                Text::new("avg: --"),
+                small_text_font(),
+                TextColor(colors::TEXT_SECONDARY),
+            ));
+            row.spawn((
+                MaxFrameTimeText,
+                Text::new("max: --"),
+                small_text_font(),
+                TextColor(colors::TEXT_SECONDARY),
+            ));
+        });
+
+    // Live p95 / p99.9 of the sample window - only shown while sampling
     parent.spawn((
-        FrameTimeText,
-        Text::new("0.00ms"),
-        heading_text_font(),
-        TextColor(colors::SUCCESS),
+        P95Text,
+        Text::new("p95: 0.00ms"),
+        small_text_font(),
+        TextColor(colors::TEXT_SECONDARY),
         Node {
-            margin: UiRect::bottom(Val::Px(8.0)),
+            display: Display::None,
+            margin: UiRect::bottom(Val::Px(4.0)),
+            ..default()
+        },
+    ));
+
+    // Slowest frame of the sample window - only shown while sampling
+    parent.spawn((
+        WorstSpikeText,
+        Text::new("worst spike: --"),
+        small_text_font(),
+        TextColor(colors::TEXT_SECONDARY),
+        Node {
+            display: Display::None,
+            margin: UiRect::bottom(Val::Px(4.0)),
+            ..default()
+        },
+    ));
+
+    // Frame-to-frame jitter (only shown while sampling)
+    parent.spawn((
+        JitterText,
+        Text::new("jitter: --"),
+        small_text_font(),
+        TextColor(colors::TEXT_SECONDARY),
+        Node {
+            display: Display::None,
+            margin: UiRect::bottom(Val::Px(4.0)),
             ..default()
         },
     ));
 
     // Target indicator
     parent.spawn((
-        Text::new(format!("Target: {:.1}ms (60 FPS)", TARGET_FRAME_TIME_MS)),
+        TargetText,
+        Text::new(format_target(TARGET_FRAME_TIME_MS)),
         small_text_font(),
         TextColor(colors::TEXT_SECONDARY),
         Node {
@@ -215,6 +398,75 @@ fn spawn_metrics_section(parent: &mut ChildSpawnerCommands) {
             ..default()
         },
     ));
+
+    // World structure
+    parent.spawn((
+        Text::new("World"),
+        small_text_font(),
+        TextColor(colors::TEXT_SECONDARY),
+    ));
+
+    parent.spawn((
+        WorldStatsText,
+        Text::new("0 archetypes | 0 tables | 0 entities"),
+        small_text_font(),
+        TextColor(colors::TEXT_PRIMARY),
+        Node {
+            margin: UiRect::bottom(Val::Px(4.0)),
+            ..default()
+        },
+    ));
+
+    parent.spawn((
+        ThreadsUsedText,
+        Text::new("threads used: 0"),
+        small_text_font(),
+        TextColor(colors::TEXT_SECONDARY),
+        Node {
+            margin: UiRect::bottom(Val::Px(16.0)),
+            ..default()
+        },
+    ));
+
+    // Structural ops - only shown for structural workloads
+    parent
+        .spawn((
+            StructuralOpsSection,
+            Node {
+                flex_direction: FlexDirection::Column,
+                display: Display::None,
+                ..default()
+            },
+        ))
+        .with_children(|section| {
+            section.spawn((
+                Text::new("Structural Ops"),
+                small_text_font(),
+                TextColor(colors::TEXT_SECONDARY),
+            ));
+
+            section.spawn((
+                StructuralOpsText,
+                Text::new("0/s"),
+                heading_text_font(),
+                TextColor(colors::ACCENT),
+                Node {
+                    margin: UiRect::bottom(Val::Px(4.0)),
+                    ..default()
+                },
+            ));
+
+            section.spawn((
+                ChurnRateText,
+                Text::new(""),
+                small_text_font(),
+                TextColor(colors::TEXT_SECONDARY),
+                Node {
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+        });
 }
 
 fn spawn_controls_section(parent: &mut ChildSpawnerCommands) {
@@ -231,13 +483,34 @@ fn spawn_controls_section(parent: &mut ChildSpawnerCommands) {
     ));
 
     let controls = [
-        ("1-6", "Select workload"),
+        ("0-9", "Select workload"),
+        ("Tab", "Next workload"),
         ("Space", "Start/Pause"),
         ("R", "Reset"),
         ("Enter", "Run full suite"),
-        ("Up/Down", "Adjust count"),
+        ("Up/Down", "Adjust count (Ctrl: x1.5)"),
+        ("T", "Cycle target FPS"),
+        ("[ / ]", "Churn rate"),
+        ("G", "Smooth graph"),
+        ("C", "Chunk size"),
+        ("B", "Bitflag checks"),
+        ("P", "Parallel prep"),
+        ("H", "Tree depth"),
+        ("U/Y/Z", "Toggle share / period / cap"),
+        ("L", "Local / Res"),
+        ("O", "Table / Sparse"),
+        ("X", "Bulk / Per-entity"),
+        ("K", "Parallel / Chained"),
+        ("F", "for_each / for loop"),
+        ("M", "EntityHashMap / component"),
+        ("W", "Components read (1-6)"),
+        ("Q", "Conditions false / true"),
+        ("N", "Step phase (--step)"),
+        ("D", "Reset config"),
+        ("E", "Export graph PNG"),
+        ("A", "Log archetypes"),
         ("S", "Save results"),
-        ("Esc", "Exit"),
+        ("Esc", "Cancel suite / Exit"),
     ];
 
     for (key, action) in controls {
@@ -262,7 +535,7 @@ fn spawn_controls_section(parent: &mut ChildSpawnerCommands) {
     }
 }
 
-fn spawn_main_content(parent: &mut ChildSpawnerCommands) {
+fn spawn_main_content(parent: &mut ChildSpawnerCommands, bar_count: usize) {
     parent
         .spawn((
             Node {
@@ -300,10 +573,41 @@ fn spawn_main_content(parent: &mut ChildSpawnerCommands) {
                 BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.3)),
             ))
             .with_children(|graph| {
-                // Spawn graph bars
-                for i in 0..300 {
+                // Current vertical axis maximum (updated as the graph rescales)
+                graph.spawn((
+                    GraphAxisLabel,
+                    Text::new("50ms"),
+                    small_text_font(),
+                    TextColor(colors::TEXT_SECONDARY),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(4.0),
+                        left: Val::Px(8.0),
+                        ..default()
+                    },
+                ));
+
+                // Target frame time line; `update_frame_graph` moves it
+                // whenever the axis rescales or the target changes
+                graph.spawn((
+                    TargetLine,
+                    Node {
+                        position_type: PositionType::Absolute,
+                        left: Val::Px(0.0),
+                        right: Val::Px(0.0),
+                        bottom: Val::Px(target_line_bottom(TARGET_FRAME_TIME_MS, 50.0)),
+                        height: Val::Px(2.0),
+                        ..default()
+                    },
+                    BackgroundColor(colors::GRAPH_TARGET),
+                    GlobalZIndex(1),
+                ));
+
+                // Spawn graph bars; `update_frame_graph` resamples the history to fit
+                for i in 0..bar_count {
                     graph.spawn((
                         GraphBar { index: i },
+                        Interaction::default(),
                         Node {
                             width: Val::Px(2.0),
                             height: Val::Px(0.0),
@@ -346,29 +650,113 @@ fn spawn_main_content(parent: &mut ChildSpawnerCommands) {
                 });
 
                 // Target legend
-                legend.spawn(Node {
+                legend
+                    .spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        align_items: AlignItems::Center,
+                        column_gap: Val::Px(8.0),
+                        ..default()
+                    })
+                    .with_children(|item| {
+                        item.spawn((
+                            Node {
+                                width: Val::Px(16.0),
+                                height: Val::Px(3.0),
+                                ..default()
+                            },
+                            BackgroundColor(colors::GRAPH_TARGET),
+                        ));
+                        item.spawn((
+                            Text::new("Target"),
+                            small_text_font(),
+                            TextColor(colors::TEXT_SECONDARY),
+                        ));
+                    });
+            });
+
+            // Throughput graph title
+            main.spawn(section_spacing());
+            main.spawn((
+                Text::new("Throughput History"),
+                heading_text_font(),
+                TextColor(colors::TEXT_PRIMARY),
+                Node {
+                    margin: UiRect::bottom(Val::Px(16.0)),
+                    ..default()
+                },
+            ));
+
+            // Throughput graph container, bar for bar with the frame time graph
+            main.spawn((
+                ThroughputGraphContainer,
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Px(120.0),
                     flex_direction: FlexDirection::Row,
-                    align_items: AlignItems::Center,
-                    column_gap: Val::Px(8.0),
+                    align_items: AlignItems::FlexEnd,
+                    column_gap: Val::Px(1.0),
+                    padding: UiRect::all(Val::Px(8.0)),
                     ..default()
-                })
-                .with_children(|item| {
-                    item.spawn((
+                },
+                BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.3)),
+            ))
+            .with_children(|graph| {
+                // Current vertical axis maximum (updated as the graph rescales)
+                graph.spawn((
+                    ThroughputAxisLabel,
+                    Text::new("0/s"),
+                    small_text_font(),
+                    TextColor(colors::TEXT_SECONDARY),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::Px(4.0),
+                        left: Val::Px(8.0),
+                        ..default()
+                    },
+                ));
+
+                // Spawn throughput bars; `update_throughput_graph` resamples the history to fit
+                for i in 0..bar_count {
+                    graph.spawn((
+                        ThroughputBar { index: i },
                         Node {
-                            width: Val::Px(16.0),
-                            height: Val::Px(3.0),
+                            width: Val::Px(2.0),
+                            height: Val::Px(0.0),
                             ..default()
                         },
-                        BackgroundColor(colors::GRAPH_TARGET),
-                    ));
-                    item.spawn((
-                        Text::new("60 FPS target"),
-                        small_text_font(),
-                        TextColor(colors::TEXT_SECONDARY),
+                        BackgroundColor(colors::ACCENT),
                     ));
-                });
+                }
             });
 
+            // Recorded results (rows are rebuilt when results change)
+            main.spawn(section_spacing());
+            main.spawn((
+                Text::new("Results"),
+                heading_text_font(),
+                TextColor(colors::TEXT_PRIMARY),
+                Node {
+                    margin: UiRect::bottom(Val::Px(8.0)),
+                    ..default()
+                },
+            ));
+            main.spawn((
+                ResultsTable,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+            ));
+            main.spawn((
+                ComparisonTable,
+                Node {
+                    flex_direction: FlexDirection::Column,
+                    row_gap: Val::Px(2.0),
+                    ..default()
+                },
+            ));
+
             // Workload selection hints
             main.spawn(section_spacing());
             spawn_workload_hints(main);
@@ -406,26 +794,28 @@ fn spawn_workload_hints(parent: &mut ChildSpawnerCommands) {
                     ..default()
                 })
                 .with_children(|item| {
-                    // Key badge
-                    item.spawn((
-                        Node {
-                            padding: UiRect::new(
-                                Val::Px(8.0),
-                                Val::Px(8.0),
-                                Val::Px(4.0),
-                                Val::Px(4.0),
-                            ),
-                            ..default()
-                        },
-                        BackgroundColor(colors::ACCENT),
-                    ))
-                    .with_children(|badge| {
-                        badge.spawn((
-                            Text::new(workload.key_hint()),
-                            small_text_font(),
-                            TextColor(colors::BACKGROUND),
-                        ));
-                    });
+                    // Key badge (workloads without a number key are cycled to with Tab)
+                    if let Some(key) = workload.key_hint() {
+                        item.spawn((
+                            Node {
+                                padding: UiRect::new(
+                                    Val::Px(8.0),
+                                    Val::Px(8.0),
+                                    Val::Px(4.0),
+                                    Val::Px(4.0),
+                                ),
+                                ..default()
+                            },
+                            BackgroundColor(colors::ACCENT),
+                        ))
+                        .with_children(|badge| {
+                            badge.spawn((
+                                Text::new(key),
+                                small_text_font(),
+                                TextColor(colors::BACKGROUND),
+                            ));
+                        });
+                    }
 
                     // Name
                     item.spawn((
@@ -464,12 +854,176 @@ pub fn update_entity_count_display(
 
 pub fn update_frame_time_display(
     metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
     query: Single<(&mut Text, &mut TextColor), With<FrameTimeText>>,
 ) {
     let (mut text, mut color) = query.into_inner();
     let frame_time = metrics.current_frame_time;
     **text = format!("{:.2}ms", frame_time);
-    color.0 = frame_time_color(frame_time, TARGET_FRAME_TIME_MS);
+    color.0 = frame_time_color(frame_time, config.target_frame_time_ms(*workload));
+}
+
+pub fn update_fps_display(
+    metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
+    query: Single<(&mut Text, &mut TextColor), With<FpsText>>,
+) {
+    let (mut text, mut color) = query.into_inner();
+    let frame_time = metrics.current_frame_time;
+    **text = if frame_time > 0.0 {
+        format!("{:.0} FPS", 1000.0 / frame_time)
+    } else {
+        "-- FPS".to_string()
+    };
+    color.0 = frame_time_color(frame_time, config.target_frame_time_ms(*workload));
+}
+
+/// Fastest frame in the rolling history
+pub fn update_min_frame_time_display(
+    metrics: Res<FrameMetrics>,
+    query: Single<&mut Text, With<MinFrameTimeText>>,
+) {
+    let mut text = query.into_inner();
+    **text = if metrics.frame_times.is_empty() {
+        "min: --".to_string()
+    } else {
+        format!("min: {:.2}ms", metrics.frame_time_range().0)
+    };
+}
+
+/// Mean frame time over the rolling history
+pub fn update_avg_frame_time_display(
+    metrics: Res<FrameMetrics>,
+    query: Single<&mut Text, With<AvgFrameTimeText>>,
+) {
+    let mut text = query.into_inner();
+    **text = if metrics.frame_times.is_empty() {
+        "avg: --".to_string()
+    } else {
+        format!("avg: {:.2}ms", metrics.average_frame_time())
+    };
+}
+
+/// Slowest frame in the rolling history
+pub fn update_max_frame_time_display(
+    metrics: Res<FrameMetrics>,
+    query: Single<&mut Text, With<MaxFrameTimeText>>,
+) {
+    let mut text = query.into_inner();
+    **text = if metrics.frame_times.is_empty() {
+        "max: --".to_string()
+    } else {
+        format!("max: {:.2}ms", metrics.frame_time_range().1)
+    };
+}
+
+/// Show the p95 and p99.9 of the in-progress sample window during `Sampling`
+pub fn update_percentile_display(
+    metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
+    phase: Res<State<BenchmarkPhase>>,
+    query: Single<(&mut Text, &mut TextColor, &mut Node), With<P95Text>>,
+) {
+    let (mut text, mut color, mut node) = query.into_inner();
+
+    let sampling = *phase.get() == BenchmarkPhase::Sampling;
+    let display = if sampling {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    if node.display != display {
+        node.display = display;
+    }
+    if !sampling {
+        return;
+    }
+
+    let p95 = metrics.running_percentile(95.0);
+    **text = format!(
+        "p95: {:.2}ms  p99.9: {:.2}ms ({} samples)",
+        p95,
+        metrics.running_percentile(99.9),
+        metrics.samples.len()
+    );
+    color.0 = frame_time_color(p95, config.target_frame_time_ms(*workload));
+}
+
+/// Show the slowest frame of the in-progress sample window during `Sampling`
+pub fn update_worst_spike_display(
+    metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
+    phase: Res<State<BenchmarkPhase>>,
+    query: Single<(&mut Text, &mut TextColor, &mut Node), With<WorstSpikeText>>,
+) {
+    let (mut text, mut color, mut node) = query.into_inner();
+
+    let sampling = *phase.get() == BenchmarkPhase::Sampling;
+    let display = if sampling {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    if node.display != display {
+        node.display = display;
+    }
+    if !sampling {
+        return;
+    }
+
+    match metrics.worst_sample() {
+        Some((frame, worst)) => {
+            **text = format!("worst spike: {:.2}ms (frame {})", worst, frame);
+            color.0 = frame_time_color(worst, config.target_frame_time_ms(*workload));
+        }
+        None => **text = "worst spike: --".to_string(),
+    }
+}
+
+/// Show the mean frame-to-frame change of the in-progress sample window during `Sampling`
+pub fn update_jitter_display(
+    metrics: Res<FrameMetrics>,
+    phase: Res<State<BenchmarkPhase>>,
+    query: Single<(&mut Text, &mut Node), With<JitterText>>,
+) {
+    let (mut text, mut node) = query.into_inner();
+
+    let sampling = *phase.get() == BenchmarkPhase::Sampling;
+    let display = if sampling {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    if node.display != display {
+        node.display = display;
+    }
+    if !sampling {
+        return;
+    }
+
+    **text = if metrics.samples.len() < 2 {
+        "jitter: --".to_string()
+    } else {
+        format!("jitter: {:.2}ms", metrics.running_jitter())
+    };
+}
+
+pub fn update_target_display(
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
+    query: Single<&mut Text, With<TargetText>>,
+) {
+    let mut text = query.into_inner();
+    **text = format_target(config.target_frame_time_ms(*workload));
+}
+
+/// Format the target label, e.g. "Target: 16.7ms (60 FPS)"
+fn format_target(target_ms: f64) -> String {
+    format!("Target: {:.1}ms ({:.0} FPS)", target_ms, 1000.0 / target_ms)
 }
 
 pub fn update_throughput_display(
@@ -480,6 +1034,78 @@ pub fn update_throughput_display(
     **text = format_throughput(metrics.throughput);
 }
 
+pub fn update_world_stats_display(
+    stats: Res<WorldStats>,
+    query: Single<&mut Text, With<WorldStatsText>>,
+) {
+    let mut text = query.into_inner();
+    **text = format!(
+        "{} archetypes ({} populated) | {} tables | {} entities",
+        stats.archetype_count,
+        stats.populated_archetype_count,
+        stats.table_count,
+        format_count(stats.entity_count)
+    );
+}
+
+/// Show how many threads the last parallelism probe ran on
+pub fn update_threads_used_display(
+    stats: Res<ParallelismStats>,
+    query: Single<&mut Text, With<ThreadsUsedText>>,
+) {
+    if !stats.is_changed() {
+        return;
+    }
+    let mut text = query.into_inner();
+    **text = format!(
+        "threads used: {} / {} (par_iter, approx.)",
+        stats.threads_used, stats.pool_threads
+    );
+}
+
+pub fn update_structural_ops_display(
+    metrics: Res<FrameMetrics>,
+    workload: Res<SelectedWorkload>,
+    section: Single<&mut Node, With<StructuralOpsSection>>,
+    text: Single<&mut Text, With<StructuralOpsText>>,
+) {
+    let mut node = section.into_inner();
+    let display = if workload.is_structural() {
+        Display::Flex
+    } else {
+        Display::None
+    };
+    if node.display != display {
+        node.display = display;
+    }
+
+    let mut text = text.into_inner();
+    **text = format_throughput(metrics.structural_ops_per_sec);
+}
+
+pub fn update_churn_rate_display(
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
+    query: Single<&mut Text, With<ChurnRateText>>,
+) {
+    let mut text = query.into_inner();
+    **text = if workload.uses_churn_rate() {
+        format!("Churn: {:.1}% / frame", config.churn_rate * 100.0)
+    } else {
+        String::new()
+    };
+}
+
+pub fn update_vsync_warning_display(
+    metrics: Res<FrameMetrics>,
+    query: Single<&mut Node, With<VsyncWarningText>>,
+) {
+    let mut node = query.into_inner();
+    if metrics.vsync_suspected && node.display == Display::None {
+        node.display = Display::Flex;
+    }
+}
+
 pub fn update_phase_display(
     phase: Res<State<BenchmarkPhase>>,
     query: Single<(&mut Text, &mut TextColor), With<PhaseText>>,
@@ -491,6 +1117,7 @@ pub fn update_phase_display(
         BenchmarkPhase::Sampling => ("Sampling", colors::ACCENT),
         BenchmarkPhase::Adjusting => ("Adjusting", colors::WARNING),
         BenchmarkPhase::Complete => ("Complete!", colors::SUCCESS),
+        BenchmarkPhase::Cooldown => ("Cooling down...", colors::TEXT_SECONDARY),
     };
     **text = phase_name.to_string();
     color.0 = phase_color;
@@ -511,3 +1138,122 @@ pub fn update_workload_description_display(
     let mut text = query.into_inner();
     **text = workload.description().to_string();
 }
+
+/// Rebuild the results table rows whenever a result is recorded.
+///
+/// Rows are ranked by throughput (see `BenchmarkReport::sorted_by_throughput`).
+/// Rows whose frame time CV exceeds `STABILITY_CV_THRESHOLD` are shown in
+/// the warning color, since their breakdown points are less trustworthy.
+pub fn update_results_table(
+    mut commands: Commands,
+    results: Res<BenchmarkResults>,
+    table: Single<Entity, With<ResultsTable>>,
+) {
+    if !results.is_changed() {
+        return;
+    }
+
+    let table = table.into_inner();
+    commands.entity(table).despawn_related::<Children>();
+
+    let Some(report) = &results.report else {
+        return;
+    };
+
+    commands.entity(table).with_children(|rows| {
+        for (rank, result) in report.sorted_by_throughput().into_iter().enumerate() {
+            let color = if result.is_noisy() {
+                colors::WARNING
+            } else {
+                colors::TEXT_SECONDARY
+            };
+            rows.spawn((
+                Text::new(format!(
+                    "{:>2}. {:<26} {:>10}  {:>10}  peak {:>18}  work {:>10}  mem {:>9}  CV {:.3}",
+                    rank + 1,
+                    result.display_name(),
+                    result.breakdown_label(),
+                    format_throughput(result.throughput_at_breakdown),
+                    result.peak_label(),
+                    format_throughput(result.work_units_per_sec),
+                    format_bytes(result.total_bytes()),
+                    result.stability_cv
+                )),
+                small_text_font(),
+                TextColor(color),
+            ));
+        }
+
+        if !report.results.is_empty() {
+            rows.spawn((
+                Text::new(format!(
+                    "Total duration: {}",
+                    format_duration(report.total_duration_seconds())
+                )),
+                small_text_font(),
+                TextColor(colors::TEXT_PRIMARY),
+                Node {
+                    margin: UiRect::top(Val::Px(4.0)),
+                    ..default()
+                },
+            ));
+        }
+    });
+}
+
+/// Fill the comparison table once `--compare` reports are loaded.
+///
+/// Improvements (B faster than A) are green, regressions red.
+pub fn update_comparison_table(
+    mut commands: Commands,
+    comparison: Option<Res<ReportComparison>>,
+    table: Single<Entity, With<ComparisonTable>>,
+) {
+    let Some(comparison) = comparison.filter(|c| c.is_added()) else {
+        return;
+    };
+
+    let table = table.into_inner();
+    commands.entity(table).despawn_related::<Children>();
+
+    commands.entity(table).with_children(|rows| {
+        rows.spawn((
+            Text::new(format!(
+                "{:<26} {:>10}  {:>10}  {:>8}",
+                "Workload", "A", "B", "Change"
+            )),
+            small_text_font(),
+            TextColor(colors::TEXT_PRIMARY),
+        ));
+        rows.spawn((
+            Text::new(format!(
+                "A: {}  B: {}",
+                comparison.label_a, comparison.label_b
+            )),
+            small_text_font(),
+            TextColor(colors::TEXT_SECONDARY),
+        ));
+
+        for row in &comparison.rows {
+            let throughput = |value: Option<f64>| value.map_or("-".to_string(), format_throughput);
+            let change = row.change_percent();
+            let color = match change {
+                Some(change) if change > 0.0 => colors::SUCCESS,
+                Some(change) if change < 0.0 => colors::DANGER,
+                _ => colors::TEXT_SECONDARY,
+            };
+
+            rows.spawn((
+                Text::new(format!(
+                    "{:<26} {:>10}  {:>10}  {:>8}",
+                    row.name,
+                    throughput(row.throughput_a),
+                    throughput(row.throughput_b),
+                    change.map_or("-".to_string(), |c| format!("{:+.1}%", c))
+                )),
+                small_text_font(),
+                TextColor(color),
+            ));
+        }
+    });
+}
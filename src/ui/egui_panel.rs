@@ -0,0 +1,99 @@
+//! Optional egui control panel (`--features egui`).
+//!
+//! An alternative to the keyboard controls for exploring settings. It
+//! writes into the same resources and sends the same spawn/despawn
+//! messages as `handle_input`, and leaves the bevy_ui dashboard untouched.
+//! The panel is drawn every frame, so its cost is part of the measured
+//! frame times; compare results with the feature off.
+
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts, EguiPlugin, EguiPrimaryContextPass};
+
+use crate::benchmark::runner::{DespawnAllRequest, PendingRespawn};
+use crate::config::{BenchmarkConfig, MAX_CHURN_RATE, MIN_CHURN_RATE, MIN_ENTITY_COUNT};
+use crate::state::{AppState, BenchmarkPhase, BenchmarkState, SelectedWorkload};
+
+/// Largest entity count the panel's slider offers
+const PANEL_MAX_ENTITY_COUNT: usize = 10_000_000;
+
+/// Plugin adding the egui control panel
+pub struct EguiControlPanelPlugin;
+
+impl Plugin for EguiControlPanelPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<EguiPlugin>() {
+            app.add_plugins(EguiPlugin::default());
+        }
+        app.add_systems(EguiPrimaryContextPass, control_panel);
+    }
+}
+
+/// Draw the panel and apply any changes.
+///
+/// Workload and entity count changes while running restart the search the
+/// same way the number keys and Up/Down do.
+fn control_panel(
+    mut contexts: EguiContexts,
+    app_state: Res<State<AppState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut next_phase: ResMut<NextState<BenchmarkPhase>>,
+    mut workload: ResMut<SelectedWorkload>,
+    mut state: ResMut<BenchmarkState>,
+    mut config: ResMut<BenchmarkConfig>,
+    mut pending: ResMut<PendingRespawn>,
+    mut despawn_events: MessageWriter<DespawnAllRequest>,
+) -> Result {
+    let ctx = contexts.ctx_mut()?;
+    let running = *app_state.get() == AppState::Running;
+
+    let mut selected = *workload;
+    let mut entity_count = state.entity_count;
+    let mut entity_count_done = false;
+
+    egui::Window::new("Controls").show(ctx, |ui| {
+        egui::ComboBox::from_label("Workload")
+            .selected_text(selected.name())
+            .show_ui(ui, |ui| {
+                for option in SelectedWorkload::all() {
+                    ui.selectable_value(&mut selected, *option, option.name());
+                }
+            });
+
+        // Applied once the drag ends so a running search restarts only once
+        let response = ui.add(
+            egui::Slider::new(&mut entity_count, MIN_ENTITY_COUNT..=PANEL_MAX_ENTITY_COUNT)
+                .logarithmic(true)
+                .text("Entities"),
+        );
+        entity_count_done = response.drag_stopped() || (response.changed() && !response.dragged());
+
+        ui.add(egui::Slider::new(&mut config.sample_frames, 30..=600).text("Sample frames"));
+        ui.add(
+            egui::Slider::new(&mut config.churn_rate, MIN_CHURN_RATE..=MAX_CHURN_RATE)
+                .logarithmic(true)
+                .text("Churn rate (next start)"),
+        );
+    });
+
+    if selected != *workload {
+        state.cancel_suite();
+        if running {
+            despawn_events.write(DespawnAllRequest);
+            next_phase.set(BenchmarkPhase::Idle);
+            next_app_state.set(AppState::Menu);
+        }
+        *workload = selected;
+        state.reset_for_new_workload(&config, selected);
+        info!("Selected workload: {}", selected.name());
+    } else if entity_count_done && entity_count != state.entity_count {
+        state.entity_count = entity_count;
+        if running {
+            despawn_events.write(DespawnAllRequest);
+            pending.request(entity_count);
+            next_phase.set(BenchmarkPhase::WarmUp);
+        }
+        info!("Entity count: {}", entity_count);
+    }
+
+    Ok(())
+}
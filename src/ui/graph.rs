@@ -1,4 +1,4 @@
-//! Frame time graph visualization.
+//! Frame time and throughput graph visualization.
 //!
 //! # Anti-Pattern Fix: Measurement Pollution
 //!
@@ -9,18 +9,28 @@
 //! to ensure accurate ECS throughput measurements during WarmUp and Sampling.
 
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use std::ops::Range;
 
-use crate::config::{colors, TARGET_FRAME_TIME_MS};
-use crate::metrics::FrameMetrics;
-use crate::state::BenchmarkPhase;
-use crate::ui::dashboard::GraphBar;
+use crate::config::{colors, BenchmarkConfig};
+use crate::metrics::{format_throughput, FrameMetrics};
+use crate::state::{BenchmarkPhase, SelectedWorkload};
+use crate::ui::dashboard::{
+    GraphAxisLabel, GraphBar, GraphTooltip, TargetLine, ThroughputAxisLabel, ThroughputBar,
+};
 
-/// Maximum frame time to display on graph (in ms)
-const MAX_DISPLAY_TIME: f64 = 50.0;
+/// Smallest vertical axis ceiling the graph will scale down to (in ms)
+const MIN_DISPLAY_TIME: f64 = 1.0;
 
 /// Graph height in pixels
 const GRAPH_HEIGHT: f32 = 284.0; // Container height minus padding
 
+/// Graph container padding in pixels
+const GRAPH_PADDING: f32 = 8.0;
+
+/// Throughput graph height in pixels
+const THROUGHPUT_GRAPH_HEIGHT: f32 = 104.0; // Container height minus padding
+
 /// FIX: Run condition to skip graph updates during critical benchmark phases.
 ///
 /// Modifying `Node.height` on 300 graph bars every frame triggers Bevy's UI
@@ -42,26 +52,62 @@ pub fn should_update_graph(phase: Res<State<BenchmarkPhase>>) -> bool {
 /// polluting benchmark measurements with UI layout overhead.
 pub fn update_frame_graph(
     metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
     mut query: Query<(&GraphBar, &mut Node, &mut BackgroundColor)>,
+    mut target_lines: Query<&mut Node, (With<TargetLine>, Without<GraphBar>)>,
+    mut axis_labels: Query<&mut Text, With<GraphAxisLabel>>,
 ) {
-    let frame_times = metrics.frame_times_slice();
+    let raw_frame_times = metrics.frame_times_slice().iter().copied();
+    let frame_phases = metrics.frame_phases_slice();
+    let target_ms = config.target_frame_time_ms(*workload);
+
+    // Smoothing is display-only; FrameMetrics keeps the raw samples
+    let frame_times: Vec<f64> = if config.graph_smoothing_enabled {
+        exponential_moving_average(raw_frame_times, config.graph_smoothing_factor)
+    } else {
+        raw_frame_times.collect()
+    };
+
+    // Scale the axis to the largest visible frame time, keeping the target in view
+    let peak = frame_times.iter().cloned().fold(target_ms, f64::max);
+    let axis_max = nice_axis_ceiling(peak);
+
+    for mut node in &mut target_lines {
+        node.bottom = Val::Px(target_line_bottom(target_ms, axis_max));
+    }
+
+    for mut text in &mut axis_labels {
+        **text = format!("{:.0}ms", axis_max);
+    }
+
+    let bar_count = query.iter().len();
 
     for (bar, mut node, mut bg_color) in &mut query {
-        // Get the frame time for this bar index
-        let frame_time = frame_times.get(bar.index).copied().unwrap_or(0.0);
+        // Slowest frame among the history entries this bar covers, so
+        // resampling a long history doesn't hide spikes
+        let frames = bar_frames(bar.index, bar_count, frame_times.len());
+        let slowest = frames
+            .clone()
+            .max_by(|&a, &b| frame_times[a].total_cmp(&frame_times[b]));
+        let frame_time = slowest.map_or(0.0, |i| frame_times[i]);
 
-        // Calculate bar height (normalized to max display time)
-        let normalized = (frame_time / MAX_DISPLAY_TIME).clamp(0.0, 1.0);
+        // Calculate bar height (normalized to the current axis ceiling)
+        let normalized = (frame_time / axis_max).clamp(0.0, 1.0);
         let height = normalized as f32 * GRAPH_HEIGHT;
 
         node.height = Val::Px(height);
 
-        // Color based on relation to target
-        let color = if frame_time > TARGET_FRAME_TIME_MS * 1.2 {
+        // Warm-up frames are desaturated so the sampling window stands out;
+        // everything else is colored relative to the target
+        let phase = slowest.and_then(|i| frame_phases.get(i).copied());
+        let color = if phase == Some(BenchmarkPhase::WarmUp) {
+            colors::GRAPH_WARMUP
+        } else if frame_time > target_ms * 1.2 {
             colors::DANGER
-        } else if frame_time > TARGET_FRAME_TIME_MS {
+        } else if frame_time > target_ms {
             colors::WARNING
-        } else if frame_time > TARGET_FRAME_TIME_MS * 0.8 {
+        } else if frame_time > target_ms * 0.8 {
             colors::ACCENT
         } else {
             colors::GRAPH_LINE
@@ -71,8 +117,164 @@ pub fn update_frame_graph(
     }
 }
 
-/// Calculate graph statistics for display
-pub fn calculate_graph_stats(frame_times: &[f64]) -> GraphStats {
+/// Update the throughput graph bars.
+///
+/// Runs under `should_update_graph` like `update_frame_graph`, for the same
+/// reason: resizing the bars during WarmUp or Sampling would add layout work
+/// to the frames being measured.
+pub fn update_throughput_graph(
+    metrics: Res<FrameMetrics>,
+    mut query: Query<(&ThroughputBar, &mut Node, &mut BackgroundColor)>,
+    mut axis_labels: Query<&mut Text, With<ThroughputAxisLabel>>,
+) {
+    let throughputs = metrics.throughput_history_slice();
+    let frame_phases = metrics.frame_phases_slice();
+
+    let peak = throughputs.iter().cloned().fold(0.0, f64::max);
+    let axis_max = nice_axis_ceiling(peak);
+
+    for mut text in &mut axis_labels {
+        **text = format_throughput(axis_max);
+    }
+
+    let bar_count = query.iter().len();
+
+    for (bar, mut node, mut bg_color) in &mut query {
+        // Lowest throughput among the frames this bar covers, the counterpart
+        // of the frame time graph showing the slowest frame
+        let frames = bar_frames(bar.index, bar_count, throughputs.len());
+        let lowest = frames
+            .clone()
+            .min_by(|&a, &b| throughputs[a].total_cmp(&throughputs[b]));
+        let throughput = lowest.map_or(0.0, |i| throughputs[i]);
+
+        let normalized = (throughput / axis_max).clamp(0.0, 1.0);
+        node.height = Val::Px(normalized as f32 * THROUGHPUT_GRAPH_HEIGHT);
+
+        let phase = lowest.and_then(|i| frame_phases.get(i).copied());
+        bg_color.0 = if phase == Some(BenchmarkPhase::WarmUp) {
+            colors::GRAPH_WARMUP
+        } else {
+            colors::ACCENT
+        };
+    }
+}
+
+/// Show the exact frame time of the hovered graph bar next to the cursor.
+///
+/// Shares the `should_update_graph` run condition, since moving the tooltip
+/// node also triggers a layout pass.
+pub fn update_graph_tooltip(
+    metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    bars: Query<(&GraphBar, &Interaction)>,
+    tooltip: Single<(&mut Text, &mut Node), With<GraphTooltip>>,
+) {
+    let (mut text, mut node) = tooltip.into_inner();
+    let bar_count = bars.iter().len();
+
+    let hovered = bars
+        .iter()
+        .find(|(_, interaction)| **interaction != Interaction::None)
+        .map(|(bar, _)| bar.index);
+
+    let (Some(index), Some(cursor)) = (hovered, window.cursor_position()) else {
+        if node.display != Display::None {
+            node.display = Display::None;
+        }
+        return;
+    };
+
+    let frame_times = metrics.frame_times_slice();
+    let frames = bar_frames(index, bar_count, frame_times.len());
+    let Some(frame_time) = frames
+        .clone()
+        .map(|i| frame_times[i])
+        .max_by(f64::total_cmp)
+    else {
+        node.display = Display::None;
+        return;
+    };
+
+    let target_ms = config.target_frame_time_ms(*workload);
+    let label = if frames.len() > 1 {
+        format!("#{}-{} max", frames.start, frames.end - 1)
+    } else {
+        format!("#{}", frames.start)
+    };
+    **text = format!(
+        "{}: {:.2}ms ({:+.2}ms vs target)",
+        label,
+        frame_time,
+        frame_time - target_ms
+    );
+
+    node.display = Display::Flex;
+    node.left = Val::Px(cursor.x + 12.0);
+    node.top = Val::Px(cursor.y + 12.0);
+}
+
+/// History indices drawn by bar `index` out of `bar_count`.
+///
+/// With no more frames than bars, bar `i` shows frame `i` (empty past the
+/// end); otherwise each bar covers an even share of the history.
+pub fn bar_frames(index: usize, bar_count: usize, history_len: usize) -> Range<usize> {
+    if history_len <= bar_count {
+        return index.min(history_len)..(index + 1).min(history_len);
+    }
+    let start = index * history_len / bar_count;
+    let end = ((index + 1) * history_len / bar_count).max(start + 1);
+    start..end.min(history_len)
+}
+
+/// Offset of the target line from the graph's bottom edge.
+///
+/// Absolutely positioned nodes are placed relative to the padding box,
+/// while the bars start at the content box, hence the padding offset.
+pub fn target_line_bottom(target_ms: f64, axis_max: f64) -> f32 {
+    GRAPH_PADDING + (target_ms / axis_max).clamp(0.0, 1.0) as f32 * GRAPH_HEIGHT
+}
+
+/// Exponential moving average of a series; `factor` is the weight of each new value
+pub fn exponential_moving_average(values: impl IntoIterator<Item = f64>, factor: f64) -> Vec<f64> {
+    let factor = factor.clamp(0.0, 1.0);
+    let mut average = None;
+    values
+        .into_iter()
+        .map(|value| {
+            let next = match average {
+                Some(prev) => prev + factor * (value - prev),
+                None => value,
+            };
+            average = Some(next);
+            next
+        })
+        .collect()
+}
+
+/// Round a frame time up to a "nice" axis value (1, 2 or 5 × 10^n ms)
+pub fn nice_axis_ceiling(value_ms: f64) -> f64 {
+    let value = value_ms.max(MIN_DISPLAY_TIME);
+    let magnitude = 10f64.powf(value.log10().floor());
+    let normalized = value / magnitude;
+
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+/// Calculate graph statistics for display, counting frames over `target_ms`
+pub fn calculate_graph_stats(frame_times: &[f64], target_ms: f64) -> GraphStats {
     if frame_times.is_empty() {
         return GraphStats::default();
     }
@@ -82,10 +284,7 @@ pub fn calculate_graph_stats(frame_times: &[f64]) -> GraphStats {
     let avg = frame_times.iter().sum::<f64>() / frame_times.len() as f64;
 
     // Count frames above target
-    let over_target = frame_times
-        .iter()
-        .filter(|&&t| t > TARGET_FRAME_TIME_MS)
-        .count();
+    let over_target = frame_times.iter().filter(|&&t| t > target_ms).count();
 
     let over_target_percent = (over_target as f64 / frame_times.len() as f64) * 100.0;
 
@@ -0,0 +1,119 @@
+//! Frame time graph export to PNG.
+//!
+//! Draws a clean plot of the frame time history with the `image` crate
+//! rather than screenshotting the window, so exports don't depend on the
+//! window size or include the rest of the dashboard.
+
+use bevy::prelude::*;
+use image::{ImageFormat, Rgb, RgbImage};
+use std::fs;
+use std::io::Cursor;
+
+use crate::benchmark::write_atomic;
+use crate::config::{colors, results_dir, BenchmarkConfig};
+use crate::metrics::FrameMetrics;
+use crate::state::SelectedWorkload;
+use crate::ui::{calculate_graph_stats, nice_axis_ceiling};
+
+/// Horizontal pixels per frame
+const BAR_WIDTH: u32 = 3;
+
+/// Plot area height in pixels
+const PLOT_HEIGHT: u32 = 300;
+
+/// Margin around the plot area in pixels
+const PLOT_MARGIN: u32 = 16;
+
+/// E to export the current frame time history as a PNG (not while sampling)
+pub fn export_graph_on_key(
+    metrics: Res<FrameMetrics>,
+    config: Res<BenchmarkConfig>,
+    workload: Res<SelectedWorkload>,
+) {
+    let frame_times: Vec<f64> = metrics.frame_times_slice().iter().copied().collect();
+    match export_frame_graph(&frame_times, config.target_frame_time_ms(*workload)) {
+        Ok(path) => info!("Frame graph exported to: {}", path),
+        Err(e) => error!("Failed to export frame graph: {}", e),
+    }
+}
+
+/// Render `frame_times` to a timestamped PNG in the results directory.
+///
+/// The `image` crate has no text rendering, so the caption (from
+/// `calculate_graph_stats`) is written to a `.txt` file next to the image.
+pub fn export_frame_graph(frame_times: &[f64], target_ms: f64) -> Result<String, String> {
+    if frame_times.is_empty() {
+        return Err("No frame times to export".to_string());
+    }
+
+    let dir = results_dir();
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    let stem = format!("frame_graph_{}", chrono::Utc::now().format("%Y%m%d_%H%M%S"));
+    let png_path = dir.join(format!("{}.png", stem));
+
+    let mut png = Cursor::new(Vec::new());
+    render_frame_graph(frame_times, target_ms)
+        .write_to(&mut png, ImageFormat::Png)
+        .map_err(|e| format!("Failed to encode image: {}", e))?;
+    write_atomic(&png_path, png.into_inner())?;
+
+    let caption = format!(
+        "{} frames | target {:.2}ms | {}",
+        frame_times.len(),
+        target_ms,
+        calculate_graph_stats(frame_times, target_ms).format_summary()
+    );
+    write_atomic(&dir.join(format!("{}.txt", stem)), &caption)?;
+
+    Ok(png_path.display().to_string())
+}
+
+/// Plot frame times as bars, colored relative to the target like the live graph
+pub fn render_frame_graph(frame_times: &[f64], target_ms: f64) -> RgbImage {
+    let width = frame_times.len() as u32 * BAR_WIDTH + PLOT_MARGIN * 2;
+    let height = PLOT_HEIGHT + PLOT_MARGIN * 2;
+    let mut image = RgbImage::from_pixel(width, height, to_rgb(colors::BACKGROUND));
+
+    let peak = frame_times.iter().copied().fold(target_ms, f64::max);
+    let axis_max = nice_axis_ceiling(peak);
+    let baseline = PLOT_MARGIN + PLOT_HEIGHT;
+    let scaled = |ms: f64| ((ms / axis_max).clamp(0.0, 1.0) * PLOT_HEIGHT as f64) as u32;
+
+    for (i, &frame_time) in frame_times.iter().enumerate() {
+        let color = to_rgb(if frame_time > target_ms * 1.2 {
+            colors::DANGER
+        } else if frame_time > target_ms {
+            colors::WARNING
+        } else if frame_time > target_ms * 0.8 {
+            colors::ACCENT
+        } else {
+            colors::GRAPH_LINE
+        });
+
+        let x0 = PLOT_MARGIN + i as u32 * BAR_WIDTH;
+        for y in baseline - scaled(frame_time)..baseline {
+            // Leave a 1px gap between bars
+            for x in x0..x0 + BAR_WIDTH - 1 {
+                image.put_pixel(x, y, color);
+            }
+        }
+    }
+
+    // Target line across the whole plot area
+    let target_y = baseline - scaled(target_ms).min(PLOT_HEIGHT - 1) - 1;
+    for x in PLOT_MARGIN..width - PLOT_MARGIN {
+        image.put_pixel(x, target_y, to_rgb(colors::GRAPH_TARGET));
+    }
+
+    image
+}
+
+fn to_rgb(color: Color) -> Rgb<u8> {
+    let srgba = color.to_srgba();
+    Rgb([
+        (srgba.red * 255.0).round() as u8,
+        (srgba.green * 255.0).round() as u8,
+        (srgba.blue * 255.0).round() as u8,
+    ])
+}
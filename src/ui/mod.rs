@@ -4,42 +4,81 @@
 //!
 //! # Anti-Pattern Fix: Measurement Pollution
 //!
-//! The `update_frame_graph` system now uses a run condition to skip updates
-//! during critical benchmark phases (WarmUp, Sampling), preventing UI layout
-//! overhead from polluting frame time measurements.
+//! The `update_frame_graph` and `update_throughput_graph` systems use a run
+//! condition to skip updates during critical benchmark phases (WarmUp,
+//! Sampling), preventing UI layout overhead from polluting frame time
+//! measurements.
 
 mod dashboard;
+#[cfg(feature = "egui")]
+mod egui_panel;
 mod graph;
+mod graph_export;
 mod styles;
 
 pub use dashboard::*;
+#[cfg(feature = "egui")]
+pub use egui_panel::*;
 pub use graph::*;
+pub use graph_export::*;
 pub use styles::{
     badge_node, body_text_font, column_node, divider, format_frame_time, frame_time_color,
     heading_text_font, labeled_value, large_metric_font, panel_node, row_node, section_spacing,
     small_text_font, title_text_font,
 };
 
+use bevy::input::common_conditions::input_just_pressed;
 use bevy::prelude::*;
 
+use crate::state::BenchmarkPhase;
+
 /// Plugin for benchmark UI
 pub struct BenchmarkUiPlugin;
 
 impl Plugin for BenchmarkUiPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(Startup, setup_ui)
+            .add_systems(Update, update_comparison_table)
+            // Encoding and writing the PNG would land inside a measured frame
+            .add_systems(
+                Update,
+                export_graph_on_key
+                    .run_if(input_just_pressed(KeyCode::KeyE))
+                    .run_if(not(in_state(BenchmarkPhase::Sampling))),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_min_frame_time_display,
+                    update_avg_frame_time_display,
+                    update_max_frame_time_display,
+                    update_jitter_display,
+                ),
+            )
             .add_systems(
                 Update,
                 (
                     update_entity_count_display,
                     update_frame_time_display,
+                    update_fps_display,
+                    update_percentile_display,
+                    update_worst_spike_display,
+                    update_target_display,
                     update_throughput_display,
+                    update_world_stats_display,
+                    update_threads_used_display,
+                    update_structural_ops_display,
+                    update_churn_rate_display,
+                    update_vsync_warning_display,
                     update_phase_display,
                     update_workload_display,
                     update_workload_description_display,
+                    update_results_table,
                     // FIX: Graph updates skip WarmUp/Sampling phases to avoid
                     // polluting benchmark measurements with UI layout overhead
                     update_frame_graph.run_if(should_update_graph),
+                    update_graph_tooltip.run_if(should_update_graph),
+                    update_throughput_graph.run_if(should_update_graph),
                 ),
             );
     }